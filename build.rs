@@ -0,0 +1,133 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Generates the tests of every day registered in `src/years.rs`: one checking the recorded
+//! answers, and one per part of each example in `src/examples/<year>/` that has an expectation.
+//! See `src/examples.rs` for the file layout.
+//!
+//! The tests of day `NN` of every year go to `$OUT_DIR/tests/NN.rs`, which the binary
+//! `src/bin/NN.rs` includes, so `cargo test --bin NN` runs only that day.
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs};
+
+/// `(year, day)` of every `Day::new::<yYYYY::dayNN::DayNN>()` in the registry.
+fn registered_days(registry: &str) -> Vec<(i16, u8)> {
+    registry
+        .lines()
+        .filter_map(|line| {
+            let path = line
+                .trim()
+                .strip_prefix("Day::new::<")?
+                .strip_suffix(">(),")?;
+            let mut segments = path.split("::");
+            let year = segments.next()?.strip_prefix('y')?.parse().ok()?;
+            let day = segments.next()?.strip_prefix("day")?.parse().ok()?;
+            Some((year, day))
+        })
+        .collect()
+}
+
+/// Indices of the examples of a day: `None` for `<day>.txt`, `Some(n)` for `<day>-<n>.txt`.
+fn examples(dir: &Path, day: u8) -> Vec<Option<u8>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let prefix = format!("{:02}", day);
+    let mut examples: Vec<Option<u8>> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".txt")?.strip_prefix(&prefix)?;
+            match stem {
+                "" => Some(None),
+                stem => stem.strip_prefix('-')?.parse().ok().map(Some),
+            }
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+/// Parts with an expectation. Both if the file cannot be parsed, so the tests report why.
+fn expected_parts(path: &Path) -> Vec<(u8, &'static str)> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return vec![];
+    };
+    let table = contents.parse::<toml::Value>().ok();
+    [(1, "part_one"), (2, "part_two")]
+        .into_iter()
+        .filter(|(_, key)| table.as_ref().is_none_or(|table| table.get(key).is_some()))
+        .collect()
+}
+
+/// The tests of a day, in a module named after it.
+fn day_tests(root: &Path, year: i16, day: u8) -> String {
+    let solution = format!(
+        "advent_of_code::years::y{0}::day{1:02}::Day{1:02}",
+        year, day
+    );
+    let dir = root.join("src/examples").join(year.to_string());
+
+    let mut tests = String::new();
+    writeln!(tests, "    mod day{:02} {{", day).unwrap();
+    writeln!(
+        tests,
+        "        #[test]\n        fn answers() {{\n            advent_of_code::answers::assert_answers::<{}>();\n        }}",
+        solution
+    )
+    .unwrap();
+
+    for index in examples(&dir, day) {
+        let (name, stem) = match index {
+            None => ("example".to_owned(), format!("{:02}", day)),
+            Some(n) => (format!("example_{}", n), format!("{:02}-{}", day, n)),
+        };
+        let parts = expected_parts(&dir.join(format!("{}.toml", stem)));
+        if parts.is_empty() {
+            continue;
+        }
+
+        writeln!(tests, "        mod {} {{", name).unwrap();
+        for (part, key) in parts {
+            writeln!(
+                tests,
+                "            #[test]\n            fn {}() {{\n                advent_of_code::examples::assert_example::<{}>({:?}, {});\n            }}",
+                key, solution, index, part
+            )
+            .unwrap();
+        }
+        writeln!(tests, "        }}").unwrap();
+    }
+    writeln!(tests, "    }}").unwrap();
+    tests
+}
+
+fn main() {
+    let root = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = Path::new(&root);
+    println!("cargo:rerun-if-changed=src/years.rs");
+    println!("cargo:rerun-if-changed=src/examples");
+
+    let registry = fs::read_to_string(root.join("src/years.rs")).unwrap_or_default();
+    let mut days: BTreeMap<u8, Vec<i16>> = BTreeMap::new();
+    for (year, day) in registered_days(&registry) {
+        days.entry(day).or_default().push(year);
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir).join("tests");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    // every binary includes its file, even if no year has registered the day yet.
+    for day in 1..=25 {
+        let mut tests = String::new();
+        for &year in days.get(&day).into_iter().flatten() {
+            writeln!(tests, "mod y{} {{", year).unwrap();
+            tests.push_str(&day_tests(root, year, day));
+            writeln!(tests, "}}").unwrap();
+        }
+        fs::write(out_dir.join(format!("{:02}.rs", day)), tests).unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    input::{project_root, InputError},
+    Solution,
+};
+
+/// Known-correct answers to the real puzzle inputs, keyed by year, day and part:
+///
+/// ```toml
+/// [2022.1]
+/// 1 = 24000
+/// 2 = "45000"
+/// ```
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// The file is valid TOML but does not follow the `[year.day] part = answer` layout.
+    Layout(String),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(e) => write!(f, "could not read \"{}\": {}", ANSWERS_PATH, e),
+            AnswersError::Parse(e) => write!(f, "could not parse \"{}\": {}", ANSWERS_PATH, e),
+            AnswersError::Layout(e) => write!(f, "unexpected entry in \"{}\": {}", ANSWERS_PATH, e),
+        }
+    }
+}
+
+/// How an answer compares to the one recorded in [`ANSWERS_PATH`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong(String),
+    /// No answer is recorded for this part.
+    #[default]
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✅"),
+            Verdict::Wrong(expected) => write!(f, "❌ expected {}", expected),
+            Verdict::Unknown => write!(f, "❔"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(i16, u8, u8), String>);
+
+impl Answers {
+    /// Reads [`ANSWERS_PATH`] in the project root. A missing file is treated as an empty database.
+    pub fn load() -> Result<Answers, AnswersError> {
+        match fs::read_to_string(project_root().join(ANSWERS_PATH)) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(AnswersError::Io(e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers, AnswersError> {
+        let table: toml::value::Table = toml::from_str(contents).map_err(AnswersError::Parse)?;
+        let mut answers = BTreeMap::new();
+
+        for (year, days) in &table {
+            let year: i16 = parse_key(year)?;
+            for (day, parts) in as_table(days, year.to_string())? {
+                let day: u8 = parse_key(day)?;
+                for (part, answer) in as_table(parts, format!("{}.{}", year, day))? {
+                    let part: u8 = parse_key(part)?;
+                    let answer = match answer {
+                        toml::Value::String(answer) => answer.to_owned(),
+                        toml::Value::Integer(answer) => answer.to_string(),
+                        other => {
+                            return Err(AnswersError::Layout(format!(
+                                "answer to {}.{}.{} is a {}",
+                                year,
+                                day,
+                                part,
+                                other.type_str()
+                            )))
+                        }
+                    };
+                    answers.insert((year, day, part), answer);
+                }
+            }
+        }
+
+        Ok(Answers(answers))
+    }
+
+    pub fn get(&self, year: i16, day: u8, part: u8) -> Option<&str> {
+        self.0.get(&(year, day, part)).map(String::as_str)
+    }
+
+    /// Whether an answer to either part of a day is recorded.
+    pub fn has_day(&self, year: i16, day: u8) -> bool {
+        self.0
+            .range((year, day, 0)..=(year, day, u8::MAX))
+            .next()
+            .is_some()
+    }
+
+    /// Compares an answer against the recorded one.
+    /// An unsolved part (`None`) counts as wrong if an answer was recorded for it.
+    pub fn check(&self, year: i16, day: u8, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(year, day, part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Correct,
+            (Some(expected), _) => Verdict::Wrong(expected.to_owned()),
+        }
+    }
+}
+
+fn parse_key<T: std::str::FromStr>(key: &str) -> Result<T, AnswersError> {
+    key.parse()
+        .map_err(|_| AnswersError::Layout(format!("\"{}\" is not a number", key)))
+}
+
+fn as_table(value: &toml::Value, key: String) -> Result<&toml::value::Table, AnswersError> {
+    value
+        .as_table()
+        .ok_or_else(|| AnswersError::Layout(format!("{} is not a table", key)))
+}
+
+/// Checks `S` against its real input and the recorded answers.
+/// Does nothing for parts without a recorded answer, or if the input was not downloaded.
+/// Meant to be called from a solution's unit tests.
+pub fn assert_answers<S: Solution>() {
+    let input = match crate::read_file("inputs", S::YEAR, S::DAY) {
+        Ok(input) => input,
+        Err(InputError::NotFound(_)) => return,
+        Err(e) => panic!("{}", e),
+    };
+    let answers = Answers::load().unwrap_or_else(|e| panic!("{}", e));
+
+    if let Some(expected) = answers.get(S::YEAR, S::DAY, 1) {
+        let answer = S::part_one(&input).map(|answer| answer.to_string());
+        assert_eq!(answer.as_deref(), Some(expected), "part one");
+    }
+    if let Some(expected) = answers.get(S::YEAR, S::DAY, 2) {
+        let answer = S::part_two(&input).map(|answer| answer.to_string());
+        assert_eq!(answer.as_deref(), Some(expected), "part two");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("[2022.5]\n1 = \"CMZ\"\n\n[2021.01]\n2 = 1234\n").unwrap();
+        assert_eq!(answers.get(2022, 5, 1), Some("CMZ"));
+        assert_eq!(answers.get(2022, 5, 2), None);
+        assert_eq!(answers.get(2021, 1, 2), Some("1234"));
+        assert!(answers.has_day(2021, 1));
+        assert!(!answers.has_day(2021, 5));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_layout() {
+        assert!(matches!(
+            Answers::parse("[2022]\n5 = \"CMZ\"\n"),
+            Err(AnswersError::Layout(_))
+        ));
+        assert!(matches!(
+            Answers::parse("[2022.five]\n1 = \"CMZ\"\n"),
+            Err(AnswersError::Layout(_))
+        ));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse("[2022.5]\n1 = \"CMZ\"\n2 = \"MCD\"\n").unwrap();
+        assert_eq!(answers.check(2022, 5, 1, Some("CMZ")), Verdict::Correct);
+        assert_eq!(
+            answers.check(2022, 5, 1, Some("ZMC")),
+            Verdict::Wrong("CMZ".to_owned())
+        );
+        assert_eq!(
+            answers.check(2022, 5, 2, None),
+            Verdict::Wrong("MCD".to_owned())
+        );
+        assert_eq!(answers.check(2021, 5, 1, Some("CMZ")), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::time::Duration;
+use std::{fs, io, process};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{bench::BenchResult, input::project_root, runner::nanos, PartResult};
+
+/// Timings of a previous run that later runs are compared against.
+pub const BASELINE_PATH: &str = "baseline.json";
+
+#[derive(Debug)]
+pub enum BaselineError {
+    Io(io::Error),
+    Parse(serde_json::Error),
+}
+
+impl Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BaselineError::Io(e) => write!(f, "could not access \"{}\": {}", BASELINE_PATH, e),
+            BaselineError::Parse(e) => write!(f, "could not parse \"{}\": {}", BASELINE_PATH, e),
+        }
+    }
+}
+
+/// The typical time one part took in a run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+}
+
+impl Timing {
+    /// A single run is its own median. Unsolved parts have no timing.
+    pub fn from_part(result: &PartResult) -> Option<Timing> {
+        result.answer.as_ref().map(|_| Timing {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            median: result.elapsed,
+        })
+    }
+
+    pub fn from_bench(result: &BenchResult) -> Option<Timing> {
+        result.stats.map(|stats| Timing {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            median: stats.median,
+        })
+    }
+}
+
+/// A timing in the baseline, along with the run it was taken in. Saving a baseline only replaces
+/// the parts that ran, so its timings may come from different runs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedTiming {
+    #[serde(flatten)]
+    pub timing: Timing,
+    /// `git rev-parse --short HEAD` at the time of the run, suffixed with `-dirty` if there were
+    /// uncommitted changes. `None` outside of a git repository.
+    pub commit: Option<String>,
+    /// RFC 3339 timestamp of the run.
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub timings: Vec<SavedTiming>,
+}
+
+impl Baseline {
+    /// Records `timings` together with the current commit and time.
+    pub fn new(timings: Vec<Timing>) -> Baseline {
+        let commit = current_commit();
+        let created_at = chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        Baseline {
+            timings: timings
+                .into_iter()
+                .map(|timing| SavedTiming {
+                    timing,
+                    commit: commit.clone(),
+                    created_at: created_at.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Keeps the timings of `previous` for parts this run did not time, e.g. the other days when
+    /// saving a single one. They keep the commit and time of the run they were taken in.
+    pub fn merge(mut self, previous: &Baseline) -> Baseline {
+        let key = |saved: &SavedTiming| (saved.timing.year, saved.timing.day, saved.timing.part);
+        let kept: Vec<SavedTiming> = previous
+            .timings
+            .iter()
+            .filter(|before| !self.timings.iter().any(|saved| key(saved) == key(before)))
+            .cloned()
+            .collect();
+        self.timings.extend(kept);
+        self.timings.sort_by_key(key);
+        self
+    }
+
+    /// Reads [`BASELINE_PATH`] in the project root, or `None` if no baseline was saved yet.
+    pub fn load() -> Result<Option<Baseline>, BaselineError> {
+        match fs::read_to_string(project_root().join(BASELINE_PATH)) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map(Some)
+                .map_err(BaselineError::Parse),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(BaselineError::Io(e)),
+        }
+    }
+
+    /// Overwrites [`BASELINE_PATH`] in the project root.
+    pub fn save(&self) -> Result<(), BaselineError> {
+        let contents =
+            serde_json::to_string_pretty(self).expect("baselines are always serializable");
+        fs::write(project_root().join(BASELINE_PATH), contents + "\n").map_err(BaselineError::Io)
+    }
+
+    /// Lists the parts whose median moved by more than `threshold` percent in either direction.
+    /// Parts missing from either side are ignored.
+    pub fn compare(&self, timings: &[Timing], threshold: f64) -> Vec<Change> {
+        timings
+            .iter()
+            .filter_map(|timing| {
+                let before = self.timings.iter().find(|before| {
+                    let before = &before.timing;
+                    (before.year, before.day, before.part) == (timing.year, timing.day, timing.part)
+                })?;
+                let change = Change {
+                    year: timing.year,
+                    day: timing.day,
+                    part: timing.part,
+                    before: before.timing.median,
+                    after: timing.median,
+                    commit: before.commit.clone(),
+                    created_at: before.created_at.clone(),
+                };
+                (change.percent().abs() > threshold).then_some(change)
+            })
+            .collect()
+    }
+}
+
+/// A part whose median moved between the baseline and the current run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+    /// The run the part was last saved in, see [`SavedTiming`].
+    pub commit: Option<String>,
+    pub created_at: String,
+}
+
+impl Change {
+    /// Relative change of the median, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        let before = self.before.as_nanos().max(1) as f64;
+        (self.after.as_nanos() as f64 - before) / before * 100.0
+    }
+
+    pub fn is_regression(&self) -> bool {
+        self.after > self.before
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Day {:02} part {}: {:.2?} -> {:.2?} ({:+.1}%) since {} ({})",
+            if self.is_regression() { "🐢" } else { "🚀" },
+            self.day,
+            self.part,
+            self.before,
+            self.after,
+            self.percent(),
+            self.commit.as_deref().unwrap_or("unknown commit"),
+            self.created_at
+        )
+    }
+}
+
+fn current_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        process::Command::new("git")
+            .args(args)
+            .current_dir(project_root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain"]) {
+        Some(status) if !status.is_empty() => Some(format!("{}-dirty", commit)),
+        _ => Some(commit),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, part: u8, micros: u64) -> Timing {
+        Timing {
+            year: 2022,
+            day,
+            part,
+            median: Duration::from_micros(micros),
+        }
+    }
+
+    fn saved(timing: Timing, commit: &str) -> SavedTiming {
+        SavedTiming {
+            timing,
+            commit: Some(commit.to_owned()),
+            created_at: "2022-12-05T08:00:00+01:00".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_compare_respects_threshold() {
+        let baseline = Baseline {
+            timings: vec![
+                saved(timing(5, 1, 100), "3fde2a2"),
+                saved(timing(5, 2, 100), "3fde2a2"),
+                saved(timing(6, 1, 100), "d106dde"),
+            ],
+        };
+        let changes = baseline.compare(
+            &[
+                timing(5, 1, 125),
+                timing(5, 2, 105),
+                timing(6, 1, 50),
+                timing(7, 1, 1),
+            ],
+            10.0,
+        );
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].is_regression());
+        assert_eq!(changes[0].percent(), 25.0);
+        assert!(!changes[1].is_regression());
+        assert_eq!(changes[1].percent(), -50.0);
+        assert_eq!(changes[1].commit.as_deref(), Some("d106dde"));
+    }
+
+    #[test]
+    fn test_merge_keeps_untimed_parts() {
+        let previous = Baseline {
+            timings: vec![
+                saved(timing(5, 1, 100), "3fde2a2"),
+                saved(timing(5, 2, 100), "3fde2a2"),
+                saved(timing(6, 1, 100), "3fde2a2"),
+            ],
+        };
+        let current = Baseline {
+            timings: vec![
+                saved(timing(6, 1, 50), "d106dde"),
+                saved(timing(5, 2, 80), "d106dde"),
+            ],
+        };
+        assert_eq!(
+            current.merge(&previous).timings,
+            vec![
+                saved(timing(5, 1, 100), "3fde2a2"),
+                saved(timing(5, 2, 80), "d106dde"),
+                saved(timing(6, 1, 50), "d106dde"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let baseline = Baseline {
+            timings: vec![saved(timing(5, 1, 37), "3fde2a2")],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert!(json.contains(r#""median_ns":37000,"commit":"3fde2a2""#));
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+}
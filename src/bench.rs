@@ -0,0 +1,244 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::Verdict, runner::nanos, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// How long a part is benchmarked for.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Runs that are discarded before sampling starts.
+    pub warmup_runs: u32,
+    /// Sampling stops after this many runs...
+    pub max_runs: u32,
+    /// ...or once this much time was spent sampling, whichever comes first.
+    pub max_time: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup_runs: 3,
+            max_runs: 10_000,
+            max_time: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Summary statistics over the timings of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    #[serde(rename = "min_ns", with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "std_dev_ns", with = "nanos")]
+    pub std_dev: Duration,
+    /// Runs outside of 1.5 times the interquartile range.
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Computes statistics for a non-empty set of samples.
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(
+            !samples.is_empty(),
+            "cannot compute statistics of no samples"
+        );
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let runs = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        let q1 = quantile(&nanos, 0.25);
+        let q3 = quantile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Stats {
+            runs,
+            min: sorted[0],
+            median: Duration::from_nanos(quantile(&nanos, 0.5).round() as u64),
+            mean: Duration::from_nanos(mean.round() as u64),
+            std_dev: Duration::from_nanos(variance.sqrt().round() as u64),
+            outliers: nanos.iter().filter(|&&n| n < low || n > high).count(),
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// The outcome of benchmarking one part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BenchResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet, in which case it is not benchmarked.
+    pub answer: Option<String>,
+    pub stats: Option<Stats>,
+    /// Filled in by the runner from [`crate::answers::ANSWERS_PATH`].
+    #[serde(default)]
+    pub verdict: Verdict,
+}
+
+impl BenchResult {
+    /// Encodes the result as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("bench results are always serializable")
+    }
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+        match (&self.answer, &self.stats) {
+            (Some(answer), Some(stats)) => write!(
+                f,
+                "{} {} {}(median: {:.2?}, mean: {:.2?} ± {:.2?}, min: {:.2?}, {} runs, {} outliers){}",
+                answer,
+                self.verdict,
+                ANSI_ITALIC,
+                stats.median,
+                stats.mean,
+                stats.std_dev,
+                stats.min,
+                stats.runs,
+                stats.outliers,
+                ANSI_RESET
+            ),
+            _ => write!(f, "not solved. {}", self.verdict),
+        }
+    }
+}
+
+/// Runs a part repeatedly according to `config` and summarizes its timings.
+pub fn bench(day: &Day, part: u8, input: &str, config: &BenchConfig) -> BenchResult {
+    let first = day.run(part, input);
+    if first.answer.is_none() {
+        return BenchResult {
+            year: day.year,
+            day: day.day,
+            part,
+            answer: None,
+            stats: None,
+            verdict: Verdict::Unknown,
+        };
+    }
+
+    for _ in 0..config.warmup_runs {
+        day.run(part, input);
+    }
+
+    let mut samples = vec![];
+    let timer = Instant::now();
+    while samples.len() < config.max_runs.max(1) as usize
+        && (samples.is_empty() || timer.elapsed() < config.max_time)
+    {
+        samples.push(day.run(part, input).elapsed);
+    }
+
+    BenchResult {
+        year: day.year,
+        day: day.day,
+        part,
+        answer: first.answer,
+        stats: Some(Stats::from_samples(&samples)),
+        verdict: Verdict::Unknown,
+    }
+}
+
+/// Renders results of several days as a table, one row per part.
+pub fn table(results: &[BenchResult]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Answer | Verdict | Median | Mean | Std. dev. | Min | Runs | Outliers |\n\
+         | --: | ---: | -----: | :-----: | -----: | ---: | --------: | --: | ---: | -------: |\n",
+    );
+
+    for result in results {
+        let row = match (&result.answer, &result.stats) {
+            (Some(answer), Some(stats)) => format!(
+                "| {:02} | {} | {} | {} | {:.2?} | {:.2?} | {:.2?} | {:.2?} | {} | {} |\n",
+                result.day,
+                result.part,
+                answer,
+                result.verdict,
+                stats.median,
+                stats.mean,
+                stats.std_dev,
+                stats.min,
+                stats.runs,
+                stats.outliers
+            ),
+            _ => format!(
+                "| {:02} | {} | not solved | {} | | | | | | |\n",
+                result.day, result.part, result.verdict
+            ),
+        };
+        table.push_str(&row);
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_micros(v)).collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let stats = Stats::from_samples(&micros(&[14, 10, 12, 11, 13]));
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_micros(10));
+        assert_eq!(stats.median, Duration::from_micros(12));
+        assert_eq!(stats.mean, Duration::from_micros(12));
+        assert_eq!(stats.std_dev, Duration::from_nanos(1414));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn test_stats_counts_outliers() {
+        let stats = Stats::from_samples(&micros(&[10, 10, 11, 11, 12, 12, 100]));
+        assert_eq!(stats.median, Duration::from_micros(11));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    fn test_bench_respects_run_budget() {
+        let day = crate::years::get(2022, 1).unwrap();
+        let input = crate::read_file("examples", 2022, 1).unwrap();
+        let config = BenchConfig {
+            warmup_runs: 1,
+            max_runs: 5,
+            max_time: Duration::from_secs(60),
+        };
+
+        let result = bench(day, 1, &input, &config);
+        assert_eq!(result.answer.as_deref(), Some("24000"));
+        assert_eq!(result.stats.map(|stats| stats.runs), Some(5));
+
+        let row = table(&[result]).lines().nth(2).unwrap().to_owned();
+        assert!(row.starts_with("| 01 | 1 | 24000 | ❔ |"), "{}", row);
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+
+use advent_of_code::{
+    answers::ANSWERS_PATH,
+    aoc::Client,
+    current_year,
+    input::inputs_folder,
+    read_file,
+    submit::{Attempt, Submission, SUBMISSIONS_PATH},
+    years,
+};
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: i16,
+    /// Submit with the session and input of this account instead of the default one.
+    account: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(current_year);
+    let account = args.opt_value_from_str("--account")?;
+    Ok(Args {
+        account,
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+        year,
+    })
+}
+
+fn main() {
+    let Args {
+        day,
+        part,
+        year,
+        account,
+    } = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        _ => {
+            eprintln!("Need to specify a day and a part (1 or 2). example: `cargo submit 5 1`");
+            process::exit(1);
+        }
+    };
+
+    let solution = match years::get(year, day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!("Day {} of {} is not registered.", day, year);
+            process::exit(1);
+        }
+    };
+
+    let input = match read_file(&inputs_folder(account.as_deref()), year, day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let answer = match solution.run(part, &input).answer {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Part {} of day {} is not solved yet.", part, day);
+            process::exit(1);
+        }
+    };
+
+    let client = match Client::from_env(account.as_deref()) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up the client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Submitting \"{}\" for day {}, {} part {}...",
+        answer, day, year, part
+    );
+
+    let submission = match client.submit(year, day, part, &answer) {
+        Ok(submission) => submission,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = Attempt::new(year, day, part, &answer, submission.clone())
+        .by(account.as_deref())
+        .log()
+    {
+        eprintln!(
+            "Failed to log the attempt to \"{}\": {}",
+            SUBMISSIONS_PATH, e
+        );
+    }
+
+    println!("---");
+    println!("{}", submission);
+
+    match submission {
+        // answers are only recorded for the default account's inputs.
+        Submission::Correct if account.is_some() => {}
+        Submission::Correct => println!(
+            "🎄 Record it in \"{}\" as `[{}.{}] {} = \"{}\"` to check it from now on.",
+            ANSWERS_PATH, year, day, part, answer
+        ),
+        Submission::AlreadySolved => {}
+        _ => process::exit(1),
+    }
+}
@@ -0,0 +1,638 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! The command line of `cargo solve` and `cargo all`. `main.rs` and the per-day binaries in
+//! `./bin/` both run it, so they accept the same flags.
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::process::{self, ExitCode};
+use std::time::Duration;
+
+use crate::{
+    answers::{Answers, Verdict, ANSWERS_PATH},
+    baseline::{Baseline, Timing, BASELINE_PATH},
+    bench::{self, BenchConfig, BenchResult},
+    current_year,
+    input::{inputs_folder, InputError, InputSource},
+    input_path,
+    runner::{parse_days, print_result, run_ordered, total_elapsed, Format},
+    years, Day, PartResult, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+enum Command {
+    Solve {
+        day: u8,
+        /// Set when `--input` or `--inline` is passed.
+        input: Option<InputSource>,
+    },
+    All {
+        /// Set when a selection such as `1,3,5-7` is passed.
+        days: Option<BTreeSet<u8>>,
+        /// Skip days without an input file or recorded answers.
+        only_solved: bool,
+    },
+}
+
+struct Args {
+    command: Command,
+    /// The event both commands look up days in.
+    year: i16,
+    format: Format,
+    /// Set when `--bench` is passed.
+    bench: Option<BenchConfig>,
+    /// How many days `cargo all` solves at the same time.
+    jobs: usize,
+    /// `[1, 2]` unless `--part` is passed.
+    parts: Vec<u8>,
+    /// `"examples"` when `--examples` is passed, otherwise the [`inputs_folder`] of `--account`.
+    folder: String,
+    baseline: BaselineArgs,
+}
+
+struct BaselineArgs {
+    /// Update the baseline with the timings of this run.
+    save: bool,
+    /// Report parts whose median moved compared to the baseline.
+    compare: bool,
+    /// Percentage a median has to move by to be reported.
+    threshold: f64,
+    /// Exit with a non-zero status if a part got slower.
+    fail_on_regression: bool,
+}
+
+/// What is left to check once every result was printed.
+#[derive(Default)]
+struct Summary {
+    verdicts: Vec<Verdict>,
+    timings: Vec<Timing>,
+}
+
+impl FromIterator<PartResult> for Summary {
+    fn from_iter<I: IntoIterator<Item = PartResult>>(results: I) -> Self {
+        let mut summary = Summary::default();
+        for result in results {
+            summary.timings.extend(Timing::from_part(&result));
+            summary.verdicts.push(result.verdict);
+        }
+        summary
+    }
+}
+
+impl FromIterator<BenchResult> for Summary {
+    fn from_iter<I: IntoIterator<Item = BenchResult>>(results: I) -> Self {
+        let mut summary = Summary::default();
+        for result in results {
+            summary.timings.extend(Timing::from_bench(&result));
+            summary.verdicts.push(result.verdict);
+        }
+        summary
+    }
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", part)),
+    }
+}
+
+fn parse_args(args: Vec<OsString>) -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_vec(args);
+    // already handled by `relaunch_in_release`.
+    args.contains("--release");
+    let format = if args.contains("--json") {
+        Format::Json
+    } else {
+        Format::Human
+    };
+
+    let bench = if args.contains("--bench") {
+        let defaults = BenchConfig::default();
+        Some(BenchConfig {
+            warmup_runs: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(defaults.warmup_runs),
+            max_runs: args
+                .opt_value_from_str("--max-runs")?
+                .unwrap_or(defaults.max_runs),
+            max_time: args
+                .opt_value_from_str("--max-time")?
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_time),
+        })
+    } else {
+        None
+    };
+
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(current_year);
+
+    let jobs = args
+        .opt_value_from_str(["-j", "--jobs"])?
+        .unwrap_or(1_usize)
+        .max(1);
+
+    let parts = match args.opt_value_from_fn("--part", parse_part)? {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let examples = args.contains("--examples");
+    let account: Option<String> = args.opt_value_from_str("--account")?;
+    let folder = if examples {
+        "examples".to_owned()
+    } else {
+        inputs_folder(account.as_deref())
+    };
+    let only_solved = args.contains("--only-solved");
+
+    let fail_on_regression = args.contains("--fail-on-regression");
+    let baseline = BaselineArgs {
+        save: args.contains("--save-baseline"),
+        compare: args.contains("--baseline") || fail_on_regression,
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10.0),
+        fail_on_regression,
+    };
+
+    let input = match (
+        args.opt_value_from_str::<_, String>("--input")?,
+        args.opt_value_from_str("--inline")?,
+    ) {
+        (Some(path), None) => Some(InputSource::from_arg(&path)),
+        (None, Some(input)) => Some(InputSource::Inline(input)),
+        (None, None) => None,
+        (Some(_), Some(_)) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--input and --inline cannot be used together".into(),
+            })
+        }
+    };
+
+    let command = match args.subcommand()?.as_deref() {
+        Some("solve") => Command::Solve {
+            day: args.free_from_str()?,
+            input,
+        },
+        Some("all") | None => Command::All {
+            days: args.opt_free_from_fn(parse_days)?,
+            only_solved,
+        },
+        Some(other) => {
+            return Err(pico_args::Error::ArgumentParsingFailed {
+                cause: format!("unknown command \"{}\"", other),
+            })
+        }
+    };
+
+    Ok(Args {
+        command,
+        year,
+        format,
+        bench,
+        jobs,
+        parts,
+        folder,
+        baseline,
+    })
+}
+
+/// `cargo solve` and `cargo all` cannot forward `--release` to cargo itself,
+/// so a debug build re-launches itself as an optimized build instead.
+fn relaunch_in_release(args: &[OsString]) -> ExitCode {
+    let args = args.iter().filter(|arg| *arg != "--release");
+    let status = process::Command::new("cargo")
+        .args(["run", "--release", "--bin", "advent_of_code", "--"])
+        .args(args)
+        .status();
+
+    match status {
+        Ok(status) => ExitCode::from(
+            status
+                .code()
+                .and_then(|code| code.try_into().ok())
+                .unwrap_or(1),
+        ),
+        Err(e) => {
+            eprintln!("Failed to spawn cargo: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn report_input_error(error: &InputError, format: Format) {
+    match error {
+        InputError::NotFound(path) => {
+            let message = format!("No input at \"{}\".", path.display());
+            match format {
+                Format::Human => println!("{}", message),
+                Format::Json => eprintln!("{}", message),
+            }
+        }
+        e => eprintln!("Failed to read input: {}", e),
+    }
+}
+
+/// Answers are only known for the real puzzle input of the default account, so other inputs are
+/// never checked.
+fn verdict(
+    answers: &Answers,
+    source: &InputSource,
+    day: &Day,
+    part: u8,
+    answer: Option<&str>,
+) -> Verdict {
+    match source {
+        InputSource::Puzzle { folder, .. } if folder == "inputs" => {
+            answers.check(day.year, day.day, part, answer)
+        }
+        _ => Verdict::Unknown,
+    }
+}
+
+/// Runs `parts` of `day` against its input.
+fn run_day(
+    day: &Day,
+    source: &InputSource,
+    parts: &[u8],
+    answers: &Answers,
+) -> Result<Vec<PartResult>, InputError> {
+    let input = source.read()?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let mut result = day.run(part, &input);
+            result.verdict = verdict(answers, source, day, part, result.answer.as_deref());
+            result
+        })
+        .collect())
+}
+
+/// Benchmarks `parts` of `day` against its input.
+fn bench_day(
+    day: &Day,
+    source: &InputSource,
+    parts: &[u8],
+    config: &BenchConfig,
+    answers: &Answers,
+) -> Result<Vec<BenchResult>, InputError> {
+    let input = source.read()?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let mut result = bench::bench(day, part, &input, config);
+            result.verdict = verdict(answers, source, day, part, result.answer.as_deref());
+            result
+        })
+        .collect())
+}
+
+/// The registered days of `year` that `cargo all` runs, in order.
+fn select_days(
+    year: i16,
+    selection: Option<&BTreeSet<u8>>,
+    only_solved: bool,
+    folder: &str,
+    answers: &Answers,
+) -> Vec<&'static Day> {
+    if let Some(selection) = selection {
+        for &day in selection {
+            if years::get(year, day).is_none() {
+                eprintln!("Day {} of {} is not registered, skipping it.", day, year);
+            }
+        }
+    }
+
+    years::of_year(year)
+        .filter(|day| selection.is_none_or(|selection| selection.contains(&day.day)))
+        .filter(|day| {
+            !only_solved
+                || (answers.has_day(day.year, day.day)
+                    && input_path(folder, day.year, day.day).exists())
+        })
+        .collect()
+}
+
+fn print_day_header(day: &Day) {
+    let header = if day.title.is_empty() {
+        format!("| Day {:02} |", day.day)
+    } else {
+        format!("| Day {:02}: {} |", day.day, day.title)
+    };
+    let rule = "-".repeat(header.chars().count());
+
+    println!("{}", rule);
+    println!("{}{}{}", ANSI_BOLD, header, ANSI_RESET);
+    println!("{}", rule);
+}
+
+fn bench_all(days: &[&Day], config: &BenchConfig, args: &Args, answers: &Answers) -> Summary {
+    if args.jobs > 1 {
+        eprintln!("Benchmarks run one day at a time so their timings are not skewed by contention, ignoring --jobs.");
+    }
+
+    let mut results: Vec<BenchResult> = vec![];
+    for day in days {
+        if args.format == Format::Human {
+            eprintln!("Benchmarking day {:02}...", day.day);
+        }
+        let source = InputSource::puzzle(&args.folder, day.year, day.day);
+        match bench_day(day, &source, &args.parts, config, answers) {
+            Ok(day_results) => {
+                if args.format == Format::Json {
+                    day_results
+                        .iter()
+                        .for_each(|result| println!("{}", result.to_json()));
+                }
+                results.extend(day_results);
+            }
+            Err(e) => report_input_error(&e, args.format),
+        }
+    }
+
+    if args.format == Format::Human {
+        let total: Duration = results
+            .iter()
+            .filter_map(|result| result.stats.map(|stats| stats.median))
+            .sum();
+
+        print!("{}", bench::table(&results));
+        println!(
+            "{}Total (sum of medians):{} {}{:.2?}{}",
+            ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        );
+    }
+
+    results.into_iter().collect()
+}
+
+fn run_all(days: &[&Day], args: &Args, answers: &Answers) -> Summary {
+    let mut results: Vec<PartResult> = vec![];
+    run_ordered(
+        days,
+        args.jobs,
+        |day| {
+            let source = InputSource::puzzle(&args.folder, day.year, day.day);
+            run_day(day, &source, &args.parts, answers)
+        },
+        |day, outcome| {
+            if args.format == Format::Human {
+                print_day_header(day);
+            }
+            match outcome {
+                Ok(day_results) => {
+                    for mut result in day_results {
+                        result.contended = args.jobs > 1;
+                        print_result(&result, args.format);
+                        results.push(result);
+                    }
+                }
+                Err(e) => report_input_error(&e, args.format),
+            }
+        },
+    );
+
+    if args.format == Format::Human {
+        println!(
+            "{}Total:{} {}{:.2?}{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            total_elapsed(&results),
+            ANSI_RESET
+        );
+        if args.jobs > 1 {
+            println!(
+                "Timings were measured with {} days running in parallel and may be inflated by contention. Use `--bench` for reliable numbers.",
+                args.jobs
+            );
+        }
+    }
+
+    results.into_iter().collect()
+}
+
+/// Why the timings of this run should not end up in the baseline, if they should not.
+fn unfit_for_baseline(args: &Args) -> Option<&'static str> {
+    if args.folder == "examples" {
+        return Some("it runs against the examples");
+    }
+    if matches!(args.command, Command::Solve { input: Some(_), .. }) {
+        return Some("it runs against `--input` or `--inline`");
+    }
+    // benchmarks ignore `--jobs`.
+    if args.jobs > 1 && args.bench.is_none() {
+        return Some("days running in parallel with `--jobs` skew the timings");
+    }
+    None
+}
+
+/// Compares `timings` against the saved baseline and saves them if requested.
+/// Returns how many parts got slower by more than the threshold.
+fn check_baseline(timings: &[Timing], args: &Args) -> Result<usize, String> {
+    let options = &args.baseline;
+    if !options.compare && !options.save {
+        return Ok(0);
+    }
+    if args.bench.is_none() {
+        eprintln!("Note: without `--bench`, a part's median is the time of a single run.");
+    }
+
+    // keeps stdout parseable when printing JSON.
+    let report = |line: String| match args.format {
+        Format::Human => println!("{}", line),
+        Format::Json => eprintln!("{}", line),
+    };
+
+    let mut regressions = 0;
+    if options.compare {
+        match Baseline::load() {
+            Ok(Some(baseline)) => {
+                report(format!(
+                    "{}Compared to the baseline:{}",
+                    ANSI_BOLD, ANSI_RESET
+                ));
+                let changes = baseline.compare(timings, options.threshold);
+                if changes.is_empty() {
+                    report(format!(
+                        "No part moved by more than {}%.",
+                        options.threshold
+                    ));
+                }
+                for change in &changes {
+                    report(change.to_string());
+                }
+                regressions = changes.iter().filter(|c| c.is_regression()).count();
+            }
+            Ok(None) => eprintln!(
+                "No baseline at \"{}\" yet. Pass `--save-baseline` to create one.",
+                BASELINE_PATH
+            ),
+            Err(e) => return Err(format!("Failed to load baseline: {}", e)),
+        }
+    }
+
+    if options.save {
+        let baseline = match Baseline::load() {
+            Ok(previous) => {
+                let baseline = Baseline::new(timings.to_vec());
+                match previous {
+                    Some(previous) => baseline.merge(&previous),
+                    None => baseline,
+                }
+            }
+            Err(e) => return Err(format!("Failed to load baseline: {}", e)),
+        };
+        match baseline.save() {
+            Ok(_) => report(format!(
+                "Saved {} timing(s) to \"{}\", which now holds {}.",
+                timings.len(),
+                BASELINE_PATH,
+                baseline.timings.len()
+            )),
+            Err(e) => return Err(format!("Failed to save baseline: {}", e)),
+        }
+    }
+
+    Ok(regressions)
+}
+
+/// Runs one day like `cargo solve <day>`, for the per-day binaries. `args` are the flags passed
+/// to the binary.
+pub fn solve(day: u8, args: impl IntoIterator<Item = OsString>) -> ExitCode {
+    let mut solve_args = vec![OsString::from("solve"), OsString::from(day.to_string())];
+    solve_args.extend(args);
+    run(solve_args)
+}
+
+/// Runs `cargo solve` or `cargo all`, depending on the first of `args`.
+pub fn run(args: Vec<OsString>) -> ExitCode {
+    if args.iter().any(|arg| arg == "--release") && cfg!(debug_assertions) {
+        return relaunch_in_release(&args);
+    }
+
+    let args = match parse_args(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.baseline.save {
+        if let Some(reason) = unfit_for_baseline(&args) {
+            eprintln!("Not saving a baseline of this run: {}.", reason);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to load answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let summary: Summary = match (&args.command, args.bench) {
+        (Command::Solve { day, input }, bench) => {
+            let day = match years::get(args.year, *day) {
+                Some(day) => day,
+                None => {
+                    eprintln!(
+                        "Day {} of {} is not registered. Type `cargo scaffold {} --year {}` to create it.",
+                        day, args.year, day, args.year
+                    );
+                    return ExitCode::FAILURE;
+                }
+            };
+            let source = input
+                .clone()
+                .unwrap_or_else(|| InputSource::puzzle(&args.folder, day.year, day.day));
+
+            let summary = match bench {
+                Some(config) => {
+                    bench_day(day, &source, &args.parts, &config, &answers).map(|results| {
+                        results
+                            .into_iter()
+                            .inspect(|result| match args.format {
+                                Format::Human => println!("{}", result),
+                                Format::Json => println!("{}", result.to_json()),
+                            })
+                            .collect()
+                    })
+                }
+                None => run_day(day, &source, &args.parts, &answers).map(|results| {
+                    results
+                        .into_iter()
+                        .inspect(|result| print_result(result, args.format))
+                        .collect()
+                }),
+            };
+
+            match summary {
+                Ok(summary) => summary,
+                Err(e) => {
+                    report_input_error(&e, args.format);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        (Command::All { days, only_solved }, bench) => {
+            let days = select_days(
+                args.year,
+                days.as_ref(),
+                *only_solved,
+                &args.folder,
+                &answers,
+            );
+            if days.is_empty() {
+                if years::of_year(args.year).next().is_none() {
+                    eprintln!(
+                        "No days of {} are registered. Type `cargo scaffold <day> --year {}` to create one.",
+                        args.year, args.year
+                    );
+                } else {
+                    eprintln!("No days of {} match the selection.", args.year);
+                }
+                return ExitCode::FAILURE;
+            }
+
+            match bench {
+                Some(config) => bench_all(&days, &config, &args, &answers),
+                None => run_all(&days, &args, &answers),
+            }
+        }
+    };
+
+    let regressions = match check_baseline(&summary.timings, &args) {
+        Ok(regressions) => regressions,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let wrong = summary
+        .verdicts
+        .iter()
+        .filter(|verdict| matches!(verdict, Verdict::Wrong(_)))
+        .count();
+    if wrong > 0 {
+        eprintln!("{} answer(s) did not match \"{}\".", wrong, ANSWERS_PATH);
+    }
+    if regressions > 0 && args.baseline.fail_on_regression {
+        eprintln!(
+            "{} part(s) got more than {}% slower than the baseline.",
+            regressions, args.baseline.threshold
+        );
+    }
+    if wrong > 0 || (regressions > 0 && args.baseline.fail_on_regression) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Checks solutions against their examples. The layout of the examples and their expectations is
+//! described in `tools/src/examples.rs`.
+use std::fs;
+
+pub use advent_of_code_tools::examples::*;
+
+use crate::Solution;
+
+/// Checks one part of `S` against an example.
+/// Does nothing if the example has no expectation for that part.
+/// Meant to be called from the tests `build.rs` generates.
+pub fn assert_example<S: Solution>(index: Option<u8>, part: u8) {
+    let expectations = Expectations::load(S::YEAR, S::DAY, index).unwrap_or_else(|e| {
+        let path = expectations_path(S::YEAR, S::DAY, index);
+        panic!("\"{}\": {}", path.display(), e)
+    });
+    let Some(expected) = expectations.get(part) else {
+        return;
+    };
+
+    let path = example_path(S::YEAR, S::DAY, index);
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("could not read \"{}\": {}", path.display(), e));
+    let answer = match part {
+        1 => S::part_one(&input).map(|answer| answer.to_string()),
+        _ => S::part_two(&input).map(|answer| answer.to_string()),
+    };
+    assert_eq!(
+        answer.as_deref(),
+        Some(expected),
+        "part {} of \"{}\"",
+        part,
+        path.display()
+    );
+}
@@ -1,4 +1,4 @@
 /*
  * Use this file if you want to extract helpers from your solutions.
- * Example import from this file: `use advent_of_code::helpers::example_fn;`.
+ * Example import from this file: `use crate::helpers::example_fn;`.
  */
@@ -1,94 +1,113 @@
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
- * Prefer `./helpers.rs` if you want to extract code from your solutions.
+ * Prefer `./helpers.rs` if you want to extract code from your solutions, and `./years/` for the solutions themselves.
  */
-use std::env;
-use std::fs;
+use std::fmt::Display;
+use std::time::Instant;
 
+use answers::Verdict;
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod cli;
+pub mod examples;
 pub mod helpers;
+pub mod runner;
+pub mod years;
+
+/// The tooling shared with the commands in `./tools/`, see its `lib.rs`.
+pub use advent_of_code_tools::{
+    aoc, current_year, input, launch, leaderboard, puzzle, submit, unlock,
+};
+pub use input::{input_path, read_file};
+
+pub use runner::PartResult;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-#[macro_export]
-macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
+/// A puzzle solution for a single day.
+///
+/// Implementors are unit structs living in `./years/y<year>/`, registered in `./years.rs` so the
+/// runner, tests and benchmarks can find them.
+pub trait Solution {
+    /// Year of the event the puzzle belongs to.
+    const YEAR: i16;
+    /// Day of the month the puzzle was released on.
+    const DAY: u8;
+    /// Puzzle title, as shown on the puzzle page.
+    const TITLE: &'static str;
+
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn part_one(input: &str) -> Option<Self::PartOne>;
+    fn part_two(input: &str) -> Option<Self::PartTwo>;
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// A type-erased [`Solution`], as stored in the [`years::DAYS`] registry.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: i16,
+    pub day: u8,
+    pub title: &'static str,
+    run: fn(u8, &str) -> PartResult,
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+impl Day {
+    pub const fn new<S: Solution>() -> Self {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+            run: run_part::<S>,
+        }
+    }
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+    /// Runs a single part (`1` or `2`), timing only the call into the solution.
+    pub fn run(&self, part: u8, input: &str) -> PartResult {
+        (self.run)(part, input)
+    }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
-}
+    pub fn part_one(&self, input: &str) -> Option<String> {
+        self.run(1, input).answer
+    }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+    pub fn part_two(&self, input: &str) -> Option<String> {
+        self.run(2, input).answer
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+fn run_part<S: Solution>(part: u8, input: &str) -> PartResult {
+    fn timed<T: Display>(
+        year: i16,
+        day: u8,
+        part: u8,
+        func: impl FnOnce(&str) -> Option<T>,
+        input: &str,
+    ) -> PartResult {
+        let timer = Instant::now();
+        let answer = func(input);
+        let elapsed = timer.elapsed();
+        PartResult {
+            year,
+            day,
+            part,
+            answer: answer.map(|answer| answer.to_string()),
+            elapsed,
+            verdict: Verdict::Unknown,
+            contended: false,
         }
-    })
-}
+    }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+    match part {
+        1 => timed(S::YEAR, S::DAY, part, S::part_one, input),
+        2 => timed(S::YEAR, S::DAY, part, S::part_two, input),
+        _ => panic!("day {} has no part {}", S::DAY, part),
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +115,14 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
+    fn test_registry_is_ordered() {
+        assert!(years::DAYS
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+        assert_eq!(
+            years::get(2022, 5).map(|day| day.title),
+            Some("Supply Stacks")
         );
+        assert_eq!(years::get(2021, 5).map(|day| day.title), None);
     }
 }
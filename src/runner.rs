@@ -0,0 +1,230 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Display};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answers::Verdict, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The outcome of running one part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    /// `None` if the part is not solved yet.
+    pub answer: Option<String>,
+    /// Time spent inside the solution, excluding input reads.
+    #[serde(rename = "elapsed_ns", with = "nanos")]
+    pub elapsed: Duration,
+    /// Filled in by the runner from [`crate::answers::ANSWERS_PATH`].
+    #[serde(default)]
+    pub verdict: Verdict,
+    /// Set if other solutions ran at the same time, which can inflate `elapsed`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub contended: bool,
+}
+
+impl PartResult {
+    /// Encodes the result as a single line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("part results are always serializable")
+    }
+
+    pub fn from_json(line: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(line)
+    }
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+        match &self.answer {
+            Some(answer) => write!(
+                f,
+                "{} {} {}(elapsed: {:.2?}){}",
+                answer, self.verdict, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            ),
+            None => write!(f, "not solved. {}", self.verdict),
+        }
+    }
+}
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Colored text meant for a terminal.
+    Human,
+    /// One [`PartResult`] per line, encoded as JSON.
+    Json,
+}
+
+pub fn print_result(result: &PartResult, format: Format) {
+    match format {
+        Format::Human => println!("{}", result),
+        Format::Json => println!("{}", result.to_json()),
+    }
+}
+
+/// Sums the time spent in every result.
+pub fn total_elapsed<'a>(results: impl IntoIterator<Item = &'a PartResult>) -> Duration {
+    results.into_iter().map(|result| result.elapsed).sum()
+}
+
+/// Parses a comma-separated list of days and inclusive ranges, e.g. `1,3,5-7`.
+pub fn parse_days(selection: &str) -> Result<BTreeSet<u8>, String> {
+    let parse_day = |day: &str| match day.trim().parse::<u8>() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", day.trim())),
+    };
+
+    let mut days = BTreeSet::new();
+    for item in selection.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (parse_day(from)?, parse_day(to)?);
+                if from > to {
+                    return Err(format!("\"{}\" is an empty range", item.trim()));
+                }
+                days.extend(from..=to);
+            }
+            None => {
+                days.insert(parse_day(item)?);
+            }
+        }
+    }
+
+    Ok(days)
+}
+
+/// Calls `work` on every item using up to `jobs` threads.
+/// Results are passed to `emit` on the calling thread, in the order of `items`, as soon as
+/// all items before them are done.
+pub fn run_ordered<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    work: impl Fn(&T) -> R + Sync,
+    mut emit: impl FnMut(&T, R),
+) {
+    if jobs <= 1 {
+        items.iter().for_each(|item| emit(item, work(item)));
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= items.len() || sender.send((index, work(&items[index]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut cursor = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&cursor) {
+                emit(&items[cursor], result);
+                cursor += 1;
+            }
+        }
+    });
+}
+
+/// Durations are written as integer nanoseconds so no precision is lost.
+pub(crate) mod nanos {
+    use std::time::Duration;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        PartResult {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.map(str::to_owned),
+            elapsed: Duration::from_nanos(nanos),
+            verdict: Verdict::Unknown,
+            contended: false,
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let result = result(2, Some("CMZ"), 37_030);
+        assert_eq!(
+            result.to_json(),
+            r#"{"year":2022,"day":1,"part":2,"answer":"CMZ","elapsed_ns":37030,"verdict":"unknown"}"#
+        );
+        assert_eq!(PartResult::from_json(&result.to_json()).unwrap(), result);
+    }
+
+    #[test]
+    fn test_total_elapsed_keeps_nanoseconds() {
+        let results = [result(1, Some("0"), 74), result(2, None, 50)];
+        assert_eq!(total_elapsed(&results), Duration::from_nanos(124));
+    }
+
+    #[test]
+    fn test_run_ordered_keeps_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let mut emitted = vec![];
+        run_ordered(
+            &items,
+            4,
+            |&item| {
+                // later items finish first.
+                thread::sleep(Duration::from_millis(20 - item));
+                item * 2
+            },
+            |&item, result| emitted.push((item, result)),
+        );
+        assert_eq!(
+            emitted,
+            items.iter().map(|&i| (i, i * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(
+            parse_days("1,3,5-7")
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![1, 3, 5, 6, 7]
+        );
+        assert_eq!(parse_days("4, 2-4").unwrap().len(), 3);
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("7-5").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+}
@@ -0,0 +1,24 @@
+/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every solution in `./years/` so it can be listed and run from code.
+ */
+use crate::Day;
+
+pub mod y2022;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<y2022::day01::Day01>(),
+    Day::new::<y2022::day02::Day02>(),
+    Day::new::<y2022::day03::Day03>(),
+    Day::new::<y2022::day04::Day04>(),
+    Day::new::<y2022::day05::Day05>(),
+    Day::new::<y2022::day06::Day06>(),
+];
+
+pub fn get(year: i16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn of_year(year: i16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
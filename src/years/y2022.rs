@@ -0,0 +1,10 @@
+/*
+ * This file is generated by `cargo scaffold`.
+ * It declares the solutions in `./y2022/`, which are registered in `../years.rs`.
+ */
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
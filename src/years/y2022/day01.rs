@@ -0,0 +1,54 @@
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Option<u32> {
+        // Split input by Elves marked by two new line characters
+        let value = Regex::new(r"\n\s*\n")
+            .unwrap()
+            .split(input)
+            // Each Elf contains a series of numbers split by newline characters
+            .map(|elf| -> u32 {
+                // Add the numbers together to get the calories intake
+                elf.split('\n').fold(0, |before, next| -> u32 {
+                    before + next.parse::<u32>().unwrap_or(0)
+                })
+            })
+            // Sort by how many calories they have consumed
+            .sorted_by(|a, b| Ord::cmp(b, a))
+            // Find the Elf with the largest calories intake
+            .next();
+
+        value
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        // Split input by Elves marked by two new line characters
+        let mut iter = Regex::new(r"\n\s*\n")
+            .unwrap()
+            .split(input)
+            // Each Elf contains a series of numbers split by newline characters
+            .map(|elf| -> u32 {
+                // Add the numbers together to get the calories intake
+                elf.split('\n').fold(0, |before, next| -> u32 {
+                    before + next.parse::<u32>().unwrap_or(0)
+                })
+            })
+            // Sort by how many calories they have consumed
+            .sorted_by(|a, b| Ord::cmp(b, a));
+
+        // Find the 3 Elves with the largest calories intake and add it up
+        Some(iter.next().unwrap() + iter.next().unwrap() + iter.next().unwrap())
+    }
+}
@@ -0,0 +1,110 @@
+#![allow(dead_code, clippy::bind_instead_of_map)]
+
+use std::num::ParseIntError;
+
+use itertools::{process_results, Itertools};
+use ranges::{GenericRange, Relation};
+
+use crate::Solution;
+
+enum RangeParseError {
+    LeftSideParsing(ParseIntError),
+    RightSideParsing(ParseIntError),
+    InvalidRange,
+}
+
+// Turn a string like "4-5" into a closed range [4..=5]
+fn get_range(ids: &str) -> Result<GenericRange<u32>, RangeParseError> {
+    // Split the string and for each tuple parse the number
+    match ids.split('-').next_tuple::<(&str, &str)>() {
+        Some((left, right)) => Ok(GenericRange::new_closed(
+            left // Parse the left side number, and report the error if it fails
+                .parse::<u32>()
+                .or_else(|err| Err(RangeParseError::LeftSideParsing(err)))?,
+            right // Same here but for the right side
+                .parse::<u32>()
+                .or_else(|err| Err(RangeParseError::RightSideParsing(err)))?,
+        )),
+        // If we couldn't split it or didn't find anything in the string report as invalid
+        _ => Err(RangeParseError::InvalidRange),
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: i16 = 2022;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn part_one(input: &str) -> Option<u32> {
+        process_results(
+            // Split the input into lines
+            input
+                .split('\n')
+                // Each line then contains a pair of ranges separated by a comma
+                .filter_map(|pair| pair.split(',').next_tuple::<(&str, &str)>())
+                // We then parse each range
+                .map(
+                    |(left, right)| -> Result<(GenericRange<u32>, GenericRange<u32>), RangeParseError> {
+                        Ok((get_range(left)?, get_range(right)?))
+                    },
+                ),
+            |ranges| {
+                // Once we have our pairs of ranges, we see if the overlap
+                ranges
+                    .map(
+                        |(left, right): (GenericRange<u32>, GenericRange<u32>)| -> bool {
+                            matches!(
+                                left.relation(right),
+                                // These are the four cases that we can consider a total overlap
+                                Relation::Containing { .. }
+                                    | Relation::Starting { .. }
+                                    | Relation::Ending { .. }
+                                    | Relation::Equal(_)
+                            )
+                        },
+                    )
+                    // We filter out those that don't overlap
+                    .filter(|value| *value)
+                    // And return the count of overlapping pairs
+                    .count() as u32
+            },
+        )
+        .ok()
+    }
+
+    fn part_two(input: &str) -> Option<u32> {
+        process_results(
+            input
+                .split('\n')
+                .filter_map(|pair| pair.split(',').next_tuple::<(&str, &str)>())
+                .map(
+                    |(left, right)| -> Result<(GenericRange<u32>, GenericRange<u32>), RangeParseError> {
+                        Ok((get_range(left)?, get_range(right)?))
+                    },
+                ),
+            |iter| {
+                iter.map(
+                    |(left, right): (GenericRange<u32>, GenericRange<u32>)| -> bool {
+                        matches!(
+                            left.relation(right),
+                            Relation::Containing { .. }
+                                | Relation::Starting { .. }
+                                | Relation::Ending { .. }
+                                | Relation::Equal(_)
+                                // The same as before but now we want to include ranges that overlap partially
+                                | Relation::Overlapping { .. }
+                        )
+                    },
+                )
+                .filter(|value| *value)
+                .count() as u32
+            },
+        )
+        .ok()
+    }
+}
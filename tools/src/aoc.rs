@@ -0,0 +1,434 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{input::project_root, submit::Submission};
+
+mod cache;
+#[cfg(test)]
+pub(crate) mod fake;
+
+pub use cache::Resource;
+use cache::{Cache, Throttle};
+
+/// Where puzzles are fetched from, unless `AOC_BASE_URL` is set.
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers, as requested by their maintainer.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/fspoettel/advent-of-code-rust)"
+);
+
+/// Where [`Client::from_env`] caches responses.
+pub const CACHE_DIR: &str = ".aoc_cache";
+
+/// The least time between two requests of [`Client::from_env`].
+pub const MIN_REQUEST_GAP: Duration = Duration::from_secs(1);
+
+/// Named sessions in the project root, one `name = "<cookie>"` per line. `default` is used
+/// when no account is given. Keep this file out of version control.
+pub const SESSIONS_PATH: &str = "sessions.toml";
+
+lazy_static! {
+    /// The user name in the header of every page, e.g. `<div class="user">ferris <span ...`.
+    static ref USER: Regex = Regex::new(r#"<div class="user">([^<]*)"#).unwrap();
+}
+
+#[derive(Debug)]
+pub enum AocError {
+    /// No session was found for the account, `None` being the default one.
+    MissingSession(Option<String>),
+    /// The session was rejected, most likely because it expired.
+    InvalidSession,
+    /// [`SESSIONS_PATH`] could not be parsed.
+    Config(toml::de::Error),
+    /// The server answered with an error status, e.g. `404` for a puzzle that is not unlocked.
+    Status(u16, String),
+    /// The server could not be reached.
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::MissingSession(account) => write!(
+                f,
+                "no session cookie found{}: set {}, add `{} = \"<cookie>\"` to \"{}\" or write it to \"{}\"",
+                account
+                    .as_ref()
+                    .map(|account| format!(" for account \"{}\"", account))
+                    .unwrap_or_default(),
+                session_var(account.as_deref()),
+                account.as_deref().unwrap_or("default"),
+                SESSIONS_PATH,
+                session_path(account.as_deref()).display()
+            ),
+            AocError::InvalidSession => write!(
+                f,
+                "the session cookie was rejected, it has probably expired"
+            ),
+            AocError::Config(e) => write!(f, "could not parse \"{}\": {}", SESSIONS_PATH, e),
+            AocError::Status(status, body) => {
+                write!(f, "server responded with {}: {}", status, body.trim())
+            }
+            AocError::Transport(e) => write!(f, "request failed: {}", e),
+            AocError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<ureq::Error> for AocError {
+    fn from(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => {
+                AocError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(e) => AocError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// `AOC_SESSION`, or `AOC_SESSION_<ACCOUNT>` for a named account.
+pub fn session_var(account: Option<&str>) -> String {
+    match account {
+        Some(account) => format!(
+            "AOC_SESSION_{}",
+            account
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                })
+                .collect::<String>()
+        ),
+        None => "AOC_SESSION".to_owned(),
+    }
+}
+
+/// `~/.adventofcode.session`, the same file `aoc-cli` reads, or
+/// `~/.adventofcode.<account>.session` for a named account.
+pub fn session_path(account: Option<&str>) -> PathBuf {
+    let name = match account {
+        Some(account) => format!(".adventofcode.{}.session", account),
+        None => ".adventofcode.session".to_owned(),
+    };
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join(name)
+}
+
+/// Where a session was found, so commands can tell which one they use.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionSource {
+    Env(String),
+    Config,
+    File(PathBuf),
+}
+
+impl Display for SessionSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionSource::Env(var) => write!(f, "${}", var),
+            SessionSource::Config => write!(f, "\"{}\"", SESSIONS_PATH),
+            SessionSource::File(path) => write!(f, "\"{}\"", path.display()),
+        }
+    }
+}
+
+/// Looks up the session cookie of `account`, or of the default account if `None`.
+///
+/// The first match wins: the [`session_var`] environment variable (`ADVENT_OF_CODE_SESSION` is
+/// still read for the default account), the entry in [`SESSIONS_PATH`], then [`session_path`].
+pub fn session(account: Option<&str>) -> Result<(String, SessionSource), AocError> {
+    let non_empty = |session: String| {
+        let session = session.trim().to_owned();
+        (!session.is_empty()).then_some(session)
+    };
+
+    let mut vars = vec![session_var(account)];
+    if account.is_none() {
+        vars.push("ADVENT_OF_CODE_SESSION".to_owned());
+    }
+    for var in vars {
+        if let Some(session) = env::var(&var).ok().and_then(non_empty) {
+            return Ok((session, SessionSource::Env(var)));
+        }
+    }
+
+    match fs::read_to_string(project_root().join(SESSIONS_PATH)) {
+        Ok(contents) => {
+            let mut sessions: HashMap<String, String> =
+                toml::from_str(&contents).map_err(AocError::Config)?;
+            if let Some(session) = sessions
+                .remove(account.unwrap_or("default"))
+                .and_then(non_empty)
+            {
+                return Ok((session, SessionSource::Config));
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(AocError::Io(e)),
+    }
+
+    let path = session_path(account);
+    match fs::read_to_string(&path) {
+        Ok(session) => match non_empty(session) {
+            Some(session) => Ok((session, SessionSource::File(path))),
+            None => Err(AocError::MissingSession(account.map(str::to_owned))),
+        },
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            Err(AocError::MissingSession(account.map(str::to_owned)))
+        }
+        Err(e) => Err(AocError::Io(e)),
+    }
+}
+
+/// A logged-in client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    cache: Option<Cache>,
+    throttle: Option<Throttle>,
+}
+
+impl Client {
+    /// A client that neither caches nor spaces out its requests, see [`Client::with_cache`].
+    pub fn new(session: String, base_url: &str) -> Client {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session,
+            cache: None,
+            throttle: None,
+        }
+    }
+
+    /// Caches responses in `dir` and keeps requests at least `min_gap` apart. Clients sharing
+    /// `dir`, including ones in other processes, share the cache and the gap.
+    pub fn with_cache(mut self, dir: &Path, min_gap: Duration) -> Client {
+        self.cache = Some(Cache::new(dir, &self.base_url, &self.session));
+        self.throttle = Some(Throttle::new(dir, min_gap));
+        self
+    }
+
+    /// A client for [`DEFAULT_BASE_URL`], or `AOC_BASE_URL` if set, using the [`session`] of
+    /// `account`. Responses are cached in [`CACHE_DIR`] and requests are [`MIN_REQUEST_GAP`] apart.
+    pub fn from_env(account: Option<&str>) -> Result<Client, AocError> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        let (session, _) = session(account)?;
+        Ok(Client::new(session, &base_url)
+            .with_cache(&project_root().join(CACHE_DIR), MIN_REQUEST_GAP))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn throttle(&self) -> Result<(), AocError> {
+        match &self.throttle {
+            Some(throttle) => throttle.wait().map_err(AocError::Io),
+            None => Ok(()),
+        }
+    }
+
+    fn get(&self, path: &str, resource: Resource) -> Result<String, AocError> {
+        if let Some(body) = self
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(path, resource))
+        {
+            return Ok(body);
+        }
+
+        self.throttle()?;
+        let response = self
+            .agent
+            .get(&format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        let body = response.into_string().map_err(AocError::Io)?;
+        if let Some(cache) = &self.cache {
+            cache.put(path, &body).map_err(AocError::Io)?;
+        }
+        Ok(body)
+    }
+
+    /// Checks that the session is logged in and returns the user name shown on the website.
+    /// `/settings` is a small page that redirects to the login page without a valid session.
+    pub fn validate(&self) -> Result<String, AocError> {
+        self.throttle()?;
+        let response = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .redirects(0)
+            .build()
+            .get(&format!("{}/settings", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        if response.status() != 200 {
+            return Err(AocError::InvalidSession);
+        }
+        let html = response.into_string().map_err(AocError::Io)?;
+        Ok(USER
+            .captures(&html)
+            .map(|captures| captures[1].trim().to_owned())
+            .unwrap_or_default())
+    }
+
+    /// Posts an answer and classifies the response. Submissions are never cached.
+    pub fn submit(
+        &self,
+        year: i16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocError> {
+        self.throttle()?;
+        let response = self
+            .agent
+            .post(&format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+        let html = response.into_string().map_err(AocError::Io)?;
+        let submission = Submission::from_html(&html);
+        if submission == Submission::Correct {
+            // the page now includes the next part.
+            if let Some(cache) = &self.cache {
+                cache.remove(&format!("/{}/day/{}", year, day));
+            }
+        }
+        Ok(submission)
+    }
+
+    /// Fetches the puzzle input of a day. Once fetched, an input is served from the cache.
+    pub fn input(&self, year: i16, day: u8) -> Result<String, AocError> {
+        self.get(&format!("/{}/day/{}/input", year, day), Resource::Input)
+    }
+
+    /// Fetches the puzzle page of a day, which includes part two once it is unlocked.
+    pub fn puzzle(&self, year: i16, day: u8) -> Result<String, AocError> {
+        self.get(&format!("/{}/day/{}", year, day), Resource::Puzzle)
+    }
+
+    /// Fetches the JSON of a private leaderboard. Its id is the user id of its owner.
+    pub fn leaderboard(&self, year: i16, id: u64) -> Result<String, AocError> {
+        self.get(
+            &format!("/{}/leaderboard/private/view/{}.json", year, id),
+            Resource::Leaderboard,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fake::serve_once;
+
+    #[test]
+    fn test_input_sends_session_and_user_agent() {
+        let (base_url, server) = serve_once(200, "1000\n2000\n");
+        let client = Client::new("abc123".to_owned(), &format!("{}/", base_url));
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request.method, "GET");
+        assert_eq!(request.url, "/2022/day/1/input");
+        assert_eq!(request.header("Cookie"), Some("session=abc123"));
+        assert_eq!(request.header("User-Agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn test_input_reports_status() {
+        let (base_url, server) = serve_once(
+            404,
+            "Please don't repeatedly request this endpoint before it unlocks!",
+        );
+        let client = Client::new("abc123".to_owned(), &base_url);
+
+        assert!(matches!(
+            client.input(2022, 25),
+            Err(AocError::Status(404, _))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit_posts_form() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><p>That's the right answer!  You are one gold star closer.</p></article></main>",
+        );
+        let client = Client::new("abc123".to_owned(), &base_url);
+
+        assert_eq!(
+            client.submit(2022, 5, 2, "MCD").unwrap(),
+            Submission::Correct
+        );
+
+        let request = server.join().unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.url, "/2022/day/5/answer");
+        assert_eq!(request.body, "level=2&answer=MCD");
+        assert_eq!(request.header("Cookie"), Some("session=abc123"));
+    }
+
+    #[test]
+    fn test_cached_input_is_not_fetched_again() {
+        let dir = env::temp_dir().join(format!("aoc-client-cache-{}", std::process::id()));
+        let (base_url, server) = serve_once(200, "1000\n2000\n");
+        let client = Client::new("abc123".to_owned(), &base_url).with_cache(&dir, Duration::ZERO);
+
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        server.join().unwrap();
+        // the fake server is gone, so this can only be answered from the cache.
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_reads_user_name() {
+        let (base_url, server) = serve_once(
+            200,
+            r#"<header><div class="user">ferris <span class="star-count">42*</span></div></header>"#,
+        );
+        let client = Client::new("abc123".to_owned(), &base_url);
+
+        assert_eq!(client.validate().unwrap(), "ferris");
+        assert_eq!(server.join().unwrap().url, "/settings");
+    }
+
+    #[test]
+    fn test_validate_rejects_redirect_to_login() {
+        let (base_url, server) = serve_once(302, "");
+        let client = Client::new("expired".to_owned(), &base_url);
+
+        assert!(matches!(client.validate(), Err(AocError::InvalidSession)));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_session_var() {
+        assert_eq!(session_var(None), "AOC_SESSION");
+        assert_eq!(session_var(Some("work-laptop")), "AOC_SESSION_WORK_LAPTOP");
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Keeps traffic to the website low: responses are cached on disk and requests are spaced out,
+//! across every process that shares the cache directory.
+use std::fs::{self, OpenOptions};
+use std::io::{self, Read, Seek, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::input::write_atomic;
+
+/// What was fetched, which decides how long a cached copy stays fresh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Input,
+    Puzzle,
+    Leaderboard,
+}
+
+impl Resource {
+    /// `None` if the resource never changes once fetched.
+    pub fn max_age(self) -> Option<Duration> {
+        match self {
+            Resource::Input => None,
+            // part two is added to the page once part one is solved.
+            Resource::Puzzle => Some(Duration::from_secs(5 * 60)),
+            // the website asks for private leaderboards to be fetched at most every 15 minutes.
+            Resource::Leaderboard => Some(Duration::from_secs(15 * 60)),
+        }
+    }
+}
+
+/// Responses stored by URL path, separately for each server and account.
+pub struct Cache {
+    dir: PathBuf,
+}
+
+/// 64-bit FNV-1a. Unlike `DefaultHasher`, it gives the same value with every Rust release, so
+/// the cache survives toolchain updates.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl Cache {
+    pub fn new(dir: &Path, base_url: &str, session: &str) -> Cache {
+        let key = format!("{}\n{}", base_url, session);
+        Cache {
+            dir: dir.join(format!("{:016x}", fnv1a(key.as_bytes()))),
+        }
+    }
+
+    fn path(&self, path: &str) -> PathBuf {
+        self.dir.join(path.trim_matches('/').replace('/', "_"))
+    }
+
+    /// The cached response for `path`, unless it is missing or older than allowed for `resource`.
+    pub fn get(&self, path: &str, resource: Resource) -> Option<String> {
+        let path = self.path(path);
+        let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+        let age = SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default();
+        if resource.max_age().is_some_and(|max_age| age > max_age) {
+            return None;
+        }
+        fs::read_to_string(path).ok()
+    }
+
+    pub fn put(&self, path: &str, body: &str) -> io::Result<()> {
+        write_atomic(&self.path(path), body)
+    }
+
+    /// Drops the cached response for `path`, e.g. after an answer changed what the page shows.
+    pub fn remove(&self, path: &str) {
+        let _ = fs::remove_file(self.path(path));
+    }
+}
+
+/// Keeps requests at least `gap` apart. The time of the last request is stored in a file, which
+/// is locked while waiting, so the gap also holds between processes started by a script.
+pub struct Throttle {
+    path: PathBuf,
+    gap: Duration,
+}
+
+impl Throttle {
+    pub fn new(dir: &Path, gap: Duration) -> Throttle {
+        Throttle {
+            path: dir.join("last_request"),
+            gap,
+        }
+    }
+
+    /// Blocks until a request may be sent and records it as sent.
+    pub fn wait(&self) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.path)?;
+        file.lock()?;
+
+        let mut last = String::new();
+        file.read_to_string(&mut last)?;
+        if let Ok(last) = last.trim().parse::<u64>() {
+            let last = UNIX_EPOCH + Duration::from_nanos(last);
+            // a timestamp in the future is ignored rather than waited for.
+            if let Ok(elapsed) = SystemTime::now().duration_since(last) {
+                if elapsed < self.gap {
+                    thread::sleep(self.gap - elapsed);
+                }
+            }
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        file.set_len(0)?;
+        file.rewind()?;
+        write!(file, "{}", now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::time::Instant;
+    use std::{env, process};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_cache_dir_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(
+            Cache::new(Path::new("c"), "http://localhost", "abc123").dir,
+            Cache::new(Path::new("c"), "http://localhost", "abc124").dir
+        );
+    }
+
+    #[test]
+    fn test_cache_expiry() {
+        let dir = temp_dir("cache");
+        let cache = Cache::new(&dir, "http://localhost", "abc123");
+        cache.put("/2022/day/1/input", "1000\n").unwrap();
+        cache.put("/2022/day/1", "<main></main>").unwrap();
+
+        let an_hour_ago = SystemTime::now() - Duration::from_secs(60 * 60);
+        for path in ["/2022/day/1/input", "/2022/day/1"] {
+            File::options()
+                .write(true)
+                .open(cache.path(path))
+                .unwrap()
+                .set_modified(an_hour_ago)
+                .unwrap();
+        }
+
+        assert_eq!(
+            cache.get("/2022/day/1/input", Resource::Input).as_deref(),
+            Some("1000\n")
+        );
+        assert_eq!(cache.get("/2022/day/1", Resource::Puzzle), None);
+        assert_eq!(
+            Cache::new(&dir, "http://localhost", "def456")
+                .get("/2022/day/1/input", Resource::Input),
+            None
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_throttle_keeps_gap() {
+        let dir = temp_dir("throttle");
+        let gap = Duration::from_millis(200);
+
+        let start = Instant::now();
+        Throttle::new(&dir, gap).wait().unwrap();
+        assert!(start.elapsed() < gap);
+        Throttle::new(&dir, gap).wait().unwrap();
+        assert!(start.elapsed() >= gap);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! A stand-in for the Advent of Code website, so tests never talk to the real one.
+use std::thread;
+
+/// What the client sent to the fake server.
+pub struct Recorded {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Recorded {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Serves a single request on a random local port and records it.
+/// Returns the base URL to point a [`super::Client`] at.
+pub fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<Recorded>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+    let handle = thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        let mut request_body = String::new();
+        request
+            .as_reader()
+            .read_to_string(&mut request_body)
+            .unwrap();
+        let recorded = Recorded {
+            method: request.method().to_string(),
+            url: request.url().to_owned(),
+            headers: request
+                .headers()
+                .iter()
+                .map(|h| (h.field.to_string(), h.value.to_string()))
+                .collect(),
+            body: request_body,
+        };
+        request
+            .respond(tiny_http::Response::from_string(body).with_status_code(status))
+            .unwrap();
+        recorded
+    });
+    (base_url, handle)
+}
@@ -0,0 +1,295 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+use std::{fs, process, thread};
+
+use chrono::{DateTime, Utc};
+
+use advent_of_code_tools::{
+    aoc::Client,
+    current_year,
+    input::{inputs_folder, write_atomic},
+    input_path,
+    puzzle::{self, puzzle_path},
+    unlock,
+};
+
+struct Args {
+    /// The current puzzle if not passed, while an event is running.
+    day: Option<u8>,
+    year: Option<i16>,
+    /// Also save the puzzle description as Markdown.
+    puzzle: bool,
+    /// Wait for the puzzle to unlock instead of refusing to download it.
+    wait: bool,
+    /// Download every unlocked day of the year that is missing.
+    all: bool,
+    /// Download with the session of this account instead of the default one.
+    account: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let puzzle = args.contains("--puzzle");
+    let wait = args.contains("--wait");
+    let all = args.contains("--all");
+    let account = args.opt_value_from_str("--account")?;
+    let year = args.opt_value_from_str(["-y", "--year"])?;
+    Ok(Args {
+        puzzle,
+        wait,
+        all,
+        account,
+        year,
+        day: args.opt_free_from_str()?,
+    })
+}
+
+fn client(account: Option<&str>) -> Client {
+    match Client::from_env(account) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up the client: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+/// The puzzle to download: the one passed, otherwise the one that unlocked today, or with
+/// `--wait` the next one to unlock, of `--year` if passed.
+fn target(args: &Args, now: DateTime<Utc>) -> Result<(i16, u8), String> {
+    match (args.day, args.year) {
+        (Some(day), year) => Ok((year.unwrap_or_else(current_year), day)),
+        (None, None) if args.wait => Ok(unlock::next_unlock(now)),
+        (None, Some(year)) if args.wait => match unlock::next_unlock_in(year, now) {
+            Some(day) => Ok((year, day)),
+            None => Err(format!(
+                "Every puzzle of {} is unlocked already. Pass a day to download one.",
+                year
+            )),
+        },
+        (None, year) => match unlock::current_day(now) {
+            Some((current, day)) if year.is_none_or(|year| year == current) => Ok((current, day)),
+            _ => Err(
+                "No puzzle unlocked today. Pass a day, or `--wait` to wait for the next one."
+                    .to_owned(),
+            ),
+        },
+    }
+}
+
+/// Counts down until the puzzle unlocks.
+fn wait_for_unlock(year: i16, day: u8) {
+    loop {
+        let left = unlock::time_until(year, day, Utc::now());
+        if left.is_zero() {
+            break;
+        }
+        print!(
+            "\r⏳ Day {} of {} unlocks in {} ",
+            day,
+            year,
+            unlock::format_countdown(left)
+        );
+        let _ = io::stdout().flush();
+        thread::sleep(left.min(Duration::from_secs(1)));
+    }
+    println!();
+    // so a clock running slightly fast does not ask for the puzzle too early.
+    thread::sleep(Duration::from_secs(1));
+}
+
+/// `cargo scaffold` leaves an empty input behind, anything else was downloaded before.
+fn is_downloaded(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+fn download_input(client: &Client, year: i16, day: u8, input_path: &Path) -> Result<(), String> {
+    let input = client
+        .input(year, day)
+        .map_err(|e| format!("Failed to download input: {}", e))?;
+    write_atomic(input_path, &input).map_err(|e| format!("Failed to write input file: {}", e))
+}
+
+/// Saves the description as Markdown and returns how many parts it has.
+fn download_puzzle(
+    client: &Client,
+    year: i16,
+    day: u8,
+    puzzle_path: &Path,
+) -> Result<usize, String> {
+    let html = client
+        .puzzle(year, day)
+        .map_err(|e| format!("Failed to download puzzle: {}", e))?;
+
+    let parts = puzzle::articles(&html).len();
+    if parts == 0 {
+        return Err("The puzzle page does not contain a description.".to_owned());
+    }
+
+    write_atomic(puzzle_path, &puzzle::to_markdown(&html))
+        .map_err(|e| format!("Failed to write puzzle file: {}", e))?;
+    Ok(parts)
+}
+
+/// What `--all` did with each file.
+#[derive(Default)]
+struct Summary {
+    fetched: usize,
+    skipped: usize,
+    failed: Vec<String>,
+}
+
+/// Fetches the input and description of every unlocked day of `year` that is not stored yet.
+fn download_all(client: &Client, year: i16, account: Option<&str>) -> Summary {
+    let mut summary = Summary::default();
+    let days =
+        (1..=unlock::days_in_event(year)).filter(|&day| unlock::is_unlocked(year, day, Utc::now()));
+
+    for day in days {
+        let input_path = input_path(&inputs_folder(account), year, day);
+        if is_downloaded(&input_path) {
+            summary.skipped += 1;
+        } else {
+            match download_input(client, year, day, &input_path) {
+                Ok(_) => {
+                    println!("Fetched input of day {:02}.", day);
+                    summary.fetched += 1;
+                }
+                Err(e) => summary.failed.push(format!("Day {:02}: {}", day, e)),
+            }
+        }
+
+        let puzzle_path = puzzle_path(year, day);
+        if puzzle_path.exists() {
+            summary.skipped += 1;
+        } else {
+            match download_puzzle(client, year, day, &puzzle_path) {
+                Ok(_) => {
+                    println!("Fetched puzzle of day {:02}.", day);
+                    summary.fetched += 1;
+                }
+                Err(e) => summary.failed.push(format!("Day {:02}: {}", day, e)),
+            }
+        }
+    }
+
+    summary
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if args.all {
+        if args.day.is_some() || args.wait {
+            eprintln!("`--all` downloads every unlocked day and cannot be combined with a day or `--wait`.");
+            process::exit(1);
+        }
+        let year = args.year.unwrap_or_else(current_year);
+        let client = client(args.account.as_deref());
+        println!(
+            "Downloading every unlocked day of {} from {}...",
+            year,
+            client.base_url()
+        );
+
+        let summary = download_all(&client, year, args.account.as_deref());
+        println!("---");
+        println!(
+            "🎄 Fetched {} file(s), skipped {} that already existed, {} failed.",
+            summary.fetched,
+            summary.skipped,
+            summary.failed.len()
+        );
+        for failure in &summary.failed {
+            eprintln!("{}", failure);
+        }
+        if !summary.failed.is_empty() {
+            process::exit(1);
+        }
+        return;
+    }
+
+    let (year, day) = match target(&args, Utc::now()) {
+        Ok(target) => target,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    if !(1..=unlock::days_in_event(year)).contains(&day) {
+        eprintln!(
+            "Day {} of {} does not exist, the event has {} days.",
+            day,
+            year,
+            unlock::days_in_event(year)
+        );
+        process::exit(1);
+    }
+    if !unlock::is_unlocked(year, day, Utc::now()) {
+        if !args.wait {
+            eprintln!(
+                "Day {} of {} unlocks in {}. Pass `--wait` to wait for it.",
+                day,
+                year,
+                unlock::format_countdown(unlock::time_until(year, day, Utc::now()))
+            );
+            process::exit(1);
+        }
+        wait_for_unlock(year, day);
+    }
+
+    let client = client(args.account.as_deref());
+
+    let input_path = input_path(&inputs_folder(args.account.as_deref()), year, day);
+    if is_downloaded(&input_path) {
+        println!(
+            "Input for day {}, {} is already at \"{}\", skipping download.",
+            day,
+            year,
+            input_path.display()
+        );
+    } else {
+        println!(
+            "Downloading input for day {}, {} from {}...",
+            day,
+            year,
+            client.base_url()
+        );
+        if let Err(e) = download_input(&client, year, day, &input_path) {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+        println!("---");
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+    }
+
+    // the description is what you want to read first after waiting for the unlock.
+    if args.puzzle || args.wait {
+        let puzzle_path = puzzle_path(year, day);
+        match download_puzzle(&client, year, day, &puzzle_path) {
+            Ok(parts) => println!(
+                "🎄 Successfully wrote {} part(s) of the puzzle to \"{}\".",
+                parts,
+                puzzle_path.display()
+            ),
+            Err(e) => {
+                eprintln!("{}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
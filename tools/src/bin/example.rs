@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{fs, process};
+
+use advent_of_code_tools::{
+    current_year,
+    examples::{self, Expectations},
+    input::write_atomic,
+    puzzle::{extract_example, puzzle_path, Example},
+};
+
+struct Args {
+    day: u8,
+    year: i16,
+    /// Use the code block at this index instead of guessing.
+    block: Option<usize>,
+    part_one: Option<String>,
+    part_two: Option<String>,
+    /// Save as numbered example `<day>-<index>` instead of `<day>`.
+    index: Option<u8>,
+    /// Replace an example or answers that were saved before.
+    force: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(current_year),
+        block: args.opt_value_from_str("--block")?,
+        part_one: args.opt_value_from_str("--part-one")?,
+        part_two: args.opt_value_from_str("--part-two")?,
+        index: args.opt_value_from_str("--index")?,
+        force: args.contains("--force"),
+        day: args.free_from_str()?,
+    })
+}
+
+/// The expectations to save: the saved ones, with the answers of `example` filled in where they
+/// are missing. A saved answer may have been edited by hand, so replacing it with a guess takes
+/// `force`. Answers passed with `--part-one` or `--part-two` always replace it.
+fn merge_expectations(
+    saved: Expectations,
+    example: &Example,
+    args: &Args,
+    path: &str,
+) -> Result<Expectations, String> {
+    let mut merged = saved.clone();
+    for (part, name, guess, passed) in [
+        (1, "one", &example.part_one, &args.part_one),
+        (2, "two", &example.part_two, &args.part_two),
+    ] {
+        let Some(answer) = passed.as_ref().or(guess.as_ref()) else {
+            continue;
+        };
+        match saved.get(part) {
+            Some(saved) if saved != answer && passed.is_none() && !args.force => {
+                return Err(format!(
+                    "\"{}\" expects {} for part {}, but the puzzle suggests {}. Pass `--part-{} {}` or `--force` to replace it.",
+                    path, saved, name, answer, name, answer
+                ))
+            }
+            _ => match part {
+                1 => merged.part_one = Some(answer.clone()),
+                _ => merged.part_two = Some(answer.clone()),
+            },
+        }
+    }
+    Ok(merged)
+}
+
+/// Writes the example next to its expectations. Nothing is written unless both can be saved.
+fn save(args: &Args, example: &Example) -> Result<(), String> {
+    let Some(input) = &example.input else {
+        return Err("Found no example input. Pick a code block with `--block <n>`.".to_owned());
+    };
+    let example_path = examples::example_path(args.year, args.day, args.index);
+    let saved_input = fs::read_to_string(&example_path).unwrap_or_default();
+    if !args.force && !saved_input.trim().is_empty() && saved_input != *input {
+        return Err(format!(
+            "\"{}\" already holds another example. Pass `--force` to replace it, or `--index <n>` to save this one next to it.",
+            example_path.display()
+        ));
+    }
+
+    let expectations_path = examples::expectations_path(args.year, args.day, args.index);
+    let saved = match Expectations::load(args.year, args.day, args.index) {
+        Ok(saved) => saved,
+        Err(_) if args.force => Expectations::default(),
+        Err(e) => {
+            return Err(format!(
+                "\"{}\": {}. Fix it or pass `--force` to replace it.",
+                expectations_path.display(),
+                e
+            ))
+        }
+    };
+    let expectations = merge_expectations(
+        saved,
+        example,
+        args,
+        &expectations_path.display().to_string(),
+    )?;
+
+    write_atomic(&example_path, input)
+        .map_err(|e| format!("Failed to write example file: {}", e))?;
+    println!("Wrote example to \"{}\":", example_path.display());
+    println!("{}", input.trim_end());
+
+    for (part, answer) in [
+        ("one", &expectations.part_one),
+        ("two", &expectations.part_two),
+    ] {
+        match answer {
+            Some(answer) => println!("Expecting {} for part {}.", answer, part),
+            None => println!("Found no answer to part {}.", part),
+        }
+    }
+    write_atomic(&expectations_path, &expectations.to_toml())
+        .map_err(|e| format!("Failed to write expectations file: {}", e))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let puzzle_path = puzzle_path(args.year, args.day);
+    let markdown = match fs::read_to_string(&puzzle_path) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!(
+                "Failed to read \"{}\": {}. Type `cargo download {} --year {} --puzzle` to download it.",
+                puzzle_path.display(),
+                e,
+                args.day,
+                args.year
+            );
+            process::exit(1);
+        }
+    };
+
+    let example = extract_example(&markdown, args.block);
+    if let Err(e) = save(&args, &example) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo test --bin {:02}` to check your solution against the example. Use `--block`, `--part-one` or `--part-two` if a guess is wrong.",
+        args.day
+    );
+}
@@ -0,0 +1,517 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{
+    env,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    process,
+};
+
+use advent_of_code_tools::{
+    current_year,
+    input::project_root,
+    launch::{self, LAUNCH_PATH},
+    puzzle::{self, puzzle_path},
+};
+
+const MODULE_TEMPLATE: &str = r###"use crate::Solution;
+
+pub struct Day{{day_padded}};
+
+impl Solution for Day{{day_padded}} {
+    const YEAR: i16 = {{year}};
+    const DAY: u8 = {{day}};
+    const TITLE: &'static str = "{{title}}";
+
+    type PartOne = {{part_one_type}};
+    type PartTwo = {{part_two_type}};
+
+    fn part_one(input: &str) -> Option<{{part_one_type}}> {
+        None
+    }
+
+    fn part_two(input: &str) -> Option<{{part_two_type}}> {
+        None
+    }
+}
+"###;
+
+/// Runs a day and its tests on their own. Shared by every year that has the day, so it is only
+/// created once.
+const BIN_TEMPLATE: &str = r###"/*
+ * This file is generated by `cargo scaffold`.
+ * It runs day {{day}} like `cargo solve {{day_padded}}` and accepts the same flags, e.g. `cargo run --bin {{day_padded}} -- --year <year>`,
+ * and its tests: `cargo test --bin {{day_padded}}`.
+ */
+use std::env;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    advent_of_code::cli::solve({{day}}, env::args_os().skip(1))
+}
+
+/// The example and answer tests of day {{day}} of every year, generated by `build.rs`.
+#[cfg(test)]
+mod tests {
+    include!(concat!(env!("OUT_DIR"), "/tests/{{day_padded}}.rs"));
+}
+"###;
+
+const REGISTRY_PATH: &str = "src/years.rs";
+
+/// Used instead of [`MODULE_TEMPLATE`] if it exists.
+const TEMPLATE_PATH: &str = "templates/day.rs";
+
+/// Paths are written relative to the project root, as the messages show them, and resolved here,
+/// so `cargo scaffold` works from any directory of the project.
+fn resolve(path: &str) -> PathBuf {
+    project_root().join(path)
+}
+
+/// Answer types `--answer` accepts.
+const ANSWER_TYPES: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    "String",
+];
+
+/// Used unless `--answer` or `AOC_ANSWER` is set.
+const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+fn parse_answer_type(answer_type: &str) -> Result<String, String> {
+    if ANSWER_TYPES.contains(&answer_type) {
+        Ok(answer_type.to_owned())
+    } else {
+        Err(format!(
+            "\"{}\" is not an answer type, expected one of {}",
+            answer_type,
+            ANSWER_TYPES.join(", ")
+        ))
+    }
+}
+
+/// What the placeholders of a template are replaced with.
+struct Placeholders<'a> {
+    year: i16,
+    day: u8,
+    title: &'a str,
+    part_one_type: &'a str,
+    part_two_type: &'a str,
+}
+
+/// Fills in `{{day}}`, `{{day_padded}}`, `{{year}}`, `{{title}}`, `{{part_one_type}}` and
+/// `{{part_two_type}}`. The title is escaped to fit in a string literal. Any other placeholder
+/// is an error, so typos do not go unnoticed.
+fn render(template: &str, values: &Placeholders) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "unclosed `{{` in template".to_owned())?;
+        let placeholder = rest[start + 2..start + end].trim();
+        match placeholder {
+            "day" => rendered.push_str(&values.day.to_string()),
+            "day_padded" => rendered.push_str(&format!("{:02}", values.day)),
+            "year" => rendered.push_str(&values.year.to_string()),
+            "title" => rendered.push_str(&values.title.replace('\\', "\\\\").replace('"', "\\\"")),
+            "part_one_type" => rendered.push_str(values.part_one_type),
+            "part_two_type" => rendered.push_str(values.part_two_type),
+            other => {
+                return Err(format!(
+                    "unknown placeholder `{{{{{}}}}}` in template",
+                    other
+                ))
+            }
+        }
+        rest = &rest[start + end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// The project template at [`TEMPLATE_PATH`], falling back to the built-in one.
+fn module_template() -> Result<(String, &'static str), std::io::Error> {
+    match fs::read_to_string(resolve(TEMPLATE_PATH)) {
+        Ok(template) => Ok((template, TEMPLATE_PATH)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            Ok((MODULE_TEMPLATE.to_owned(), "the built-in template"))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Lists the days that have a module in `src/years/y<year>/`, ordered by year and day.
+fn scaffolded_days() -> Result<Vec<(i16, u8)>, std::io::Error> {
+    let mut days = vec![];
+    for entry in fs::read_dir(resolve("src/years"))? {
+        let entry = entry?;
+        let year = match entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.strip_prefix('y'))
+        {
+            Some(year) if entry.path().is_dir() => match year.parse::<i16>() {
+                Ok(year) => year,
+                Err(_) => continue,
+            },
+            _ => continue,
+        };
+
+        for entry in fs::read_dir(entry.path())? {
+            let name = entry?.file_name();
+            let day = name
+                .to_str()
+                .and_then(|name| name.strip_prefix("day")?.strip_suffix(".rs")?.parse().ok());
+            if let Some(day) = day {
+                days.push((year, day));
+            }
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+/// Declares the modules of a single year in `src/years/y<year>.rs`.
+fn year_module_contents(year: i16, days: &[(i16, u8)]) -> String {
+    let mut contents = format!(
+        "/*
+ * This file is generated by `cargo scaffold`.
+ * It declares the solutions in `./y{0}/`, which are registered in `../years.rs`.
+ */
+",
+        year
+    );
+
+    for (_, day) in days.iter().filter(|(y, _)| *y == year) {
+        contents.push_str(&format!("pub mod day{:02};\n", day));
+    }
+
+    contents
+}
+
+fn registry_contents(days: &[(i16, u8)]) -> String {
+    let mut contents = String::from(
+        "/*
+ * This file is generated by `cargo scaffold`.
+ * It registers every solution in `./years/` so it can be listed and run from code.
+ */
+use crate::Day;
+
+",
+    );
+
+    let mut years: Vec<i16> = days.iter().map(|(year, _)| *year).collect();
+    years.dedup();
+    for year in years {
+        contents.push_str(&format!("pub mod y{};\n", year));
+    }
+
+    contents.push_str("\npub const DAYS: &[Day] = &[\n");
+    for (year, day) in days {
+        contents.push_str(&format!(
+            "    Day::new::<y{0}::day{1:02}::Day{1:02}>(),\n",
+            year, day
+        ));
+    }
+    contents.push_str(
+        "];
+
+pub fn get(year: i16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+pub fn of_year(year: i16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |d| d.year == year)
+}
+",
+    );
+
+    contents
+}
+
+/// Regenerates the module of `year` and the registry from the modules on disk. The module of a
+/// year without days is removed, as the registry no longer declares it.
+fn update_registry(year: i16) -> Result<(), std::io::Error> {
+    let days = scaffolded_days()?;
+    let year_module = resolve(&format!("src/years/y{}.rs", year));
+    if days.iter().any(|(y, _)| *y == year) {
+        fs::write(year_module, year_module_contents(year, &days))?;
+    } else if year_module.exists() {
+        fs::remove_file(year_module)?;
+    }
+    fs::write(resolve(REGISTRY_PATH), registry_contents(&days))
+}
+
+struct Args {
+    day: u8,
+    year: i16,
+    /// Answer type of both parts, unless overridden by `part_one_type` or `part_two_type`.
+    answer_type: Option<String>,
+    part_one_type: Option<String>,
+    part_two_type: Option<String>,
+    /// Take the day's debug configurations out again instead of scaffolding it.
+    remove: bool,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        remove: args.contains("--remove"),
+        answer_type: args.opt_value_from_fn("--answer", parse_answer_type)?,
+        part_one_type: args.opt_value_from_fn("--answer-one", parse_answer_type)?,
+        part_two_type: args.opt_value_from_fn("--answer-two", parse_answer_type)?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(current_year),
+        day: args.free_from_str()?,
+    })
+}
+
+/// `--answer`, otherwise the project default in `AOC_ANSWER`, otherwise [`DEFAULT_ANSWER_TYPE`].
+fn default_answer_type(answer_type: Option<String>) -> Result<String, String> {
+    match answer_type {
+        Some(answer_type) => Ok(answer_type),
+        None => match env::var("AOC_ANSWER") {
+            Ok(answer_type) => parse_answer_type(answer_type.trim()),
+            Err(_) => Ok(DEFAULT_ANSWER_TYPE.to_owned()),
+        },
+    }
+}
+
+fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+    let path = resolve(path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new().write(true).create_new(true).open(path)
+}
+
+fn create_file(path: &str) -> Result<File, std::io::Error> {
+    let path = resolve(path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Applies `edit` to [`LAUNCH_PATH`]. Projects without one are left alone.
+fn update_launch(edit: impl Fn(&str) -> Option<String>) -> Result<bool, String> {
+    let path = resolve(LAUNCH_PATH);
+    let json = match fs::read_to_string(&path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e.to_string()),
+    };
+    let updated = edit(&json).ok_or_else(|| "found no `configurations` array".to_owned())?;
+    if updated != json {
+        fs::write(&path, updated).map_err(|e| e.to_string())?;
+    }
+    Ok(true)
+}
+
+/// `--remove`: takes out the debug configurations of a day and unregisters it if its module
+/// was deleted. This binary does not build the solutions, so the registry may still name it.
+fn remove(year: i16, day: u8) {
+    match update_launch(|json| launch::remove_day(json, year, day)) {
+        Ok(true) => println!(
+            "Removed the debug configurations of day {} of {} from \"{}\"",
+            day, year, LAUNCH_PATH
+        ),
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Failed to update \"{}\": {}", LAUNCH_PATH, e);
+            process::exit(1);
+        }
+    }
+
+    if let Err(e) = update_registry(year) {
+        eprintln!("Failed to update registry: {}", e);
+        process::exit(1);
+    }
+    if resolve(&format!("src/years/y{}/day{:02}.rs", year, day)).exists() {
+        println!(
+            "Day {} of {} is still registered. To unregister it, delete \"src/years/y{}/day{:02}.rs\" and run this again.",
+            day, year, year, day
+        );
+        return;
+    }
+    println!(
+        "Unregistered day {} of {} in \"{}\"",
+        day, year, REGISTRY_PATH
+    );
+
+    // the binary stays as long as another year has the day.
+    let bin_path = format!("src/bin/{:02}.rs", day);
+    let in_use = scaffolded_days().is_ok_and(|days| days.iter().any(|(_, d)| *d == day));
+    if !in_use && resolve(&bin_path).exists() {
+        match fs::remove_file(resolve(&bin_path)) {
+            Ok(_) => println!("Removed binary file \"{}\"", bin_path),
+            Err(e) => eprintln!("Failed to remove binary file: {}", e),
+        }
+    }
+}
+
+fn main() {
+    let Args {
+        day,
+        year,
+        answer_type,
+        part_one_type,
+        part_two_type,
+        remove,
+    } = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    if remove {
+        self::remove(year, day);
+        return;
+    }
+
+    let answer_type = match default_answer_type(answer_type) {
+        Ok(answer_type) => answer_type,
+        Err(e) => {
+            eprintln!("Failed to read AOC_ANSWER: {}", e);
+            process::exit(1);
+        }
+    };
+    let part_one_type = part_one_type.unwrap_or_else(|| answer_type.clone());
+    let part_two_type = part_two_type.unwrap_or(answer_type);
+
+    let day_padded = format!("{:02}", day);
+
+    let input_path = format!("src/inputs/{}/{}.txt", year, day_padded);
+    let example_path = format!("src/examples/{}/{}.txt", year, day_padded);
+    let module_path = format!("src/years/y{}/day{}.rs", year, day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
+
+    let (template, template_name) = match module_template() {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", TEMPLATE_PATH, e);
+            process::exit(1);
+        }
+    };
+    // the title is known once `cargo download --puzzle` saved the description.
+    let title = fs::read_to_string(puzzle_path(year, day))
+        .ok()
+        .and_then(|markdown| puzzle::title(&markdown))
+        .unwrap_or_default();
+    let placeholders = Placeholders {
+        year,
+        day,
+        title: &title,
+        part_one_type: &part_one_type,
+        part_two_type: &part_two_type,
+    };
+    let module = match render(&template, &placeholders) {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to render {}: {}", template_name, e);
+            process::exit(1);
+        }
+    };
+
+    let mut file = match safe_create_file(&module_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create module file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(module.as_bytes()) {
+        Ok(_) => {
+            println!(
+                "Created module file \"{}\" from {}",
+                &module_path, template_name
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to write module contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match update_registry(year) {
+        Ok(_) => {
+            println!(
+                "Registered day {} of {} in \"{}\"",
+                day, year, REGISTRY_PATH
+            );
+        }
+        Err(e) => {
+            eprintln!("Failed to update registry: {}", e);
+            process::exit(1);
+        }
+    }
+
+    if !resolve(&bin_path).exists() {
+        let bin = render(BIN_TEMPLATE, &placeholders).expect("the binary template is valid");
+        match fs::write(resolve(&bin_path), bin) {
+            Ok(_) => println!("Created binary file \"{}\"", &bin_path),
+            Err(e) => {
+                eprintln!("Failed to write binary file: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+
+    match update_launch(|json| launch::add_day(json, year, day)) {
+        Ok(true) => println!("Added debug configurations to \"{}\"", LAUNCH_PATH),
+        Ok(false) => {}
+        Err(e) => eprintln!("Failed to update \"{}\": {}", LAUNCH_PATH, e),
+    }
+
+    match create_file(&input_path) {
+        Ok(_) => {
+            println!("Created empty input file \"{}\"", &input_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create input file: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match create_file(&example_path) {
+        Ok(_) => {
+            println!("Created empty example file \"{}\"", &example_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to create example file: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let year_arg = if year == current_year() {
+        String::new()
+    } else {
+        format!(" --year {}", year)
+    };
+    println!("---");
+    println!(
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        &day_padded, year_arg
+    );
+    // the tests of the day, one per example and part, are generated from its expectations.
+    println!(
+        "🎄 Type `cargo example {}{}` to save the example and its answers, then `cargo test --bin {}` to check your solution against it.",
+        day, year_arg, &day_padded
+    );
+}
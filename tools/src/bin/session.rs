@@ -0,0 +1,55 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::{env, process};
+
+use advent_of_code_tools::aoc::{self, Client, DEFAULT_BASE_URL};
+
+fn parse_args() -> Result<Option<String>, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    args.opt_value_from_str("--account")
+}
+
+fn main() {
+    let account = match parse_args() {
+        Ok(account) => account,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let name = account.as_deref().unwrap_or("default");
+
+    let source = match aoc::session(account.as_deref()) {
+        Ok((_, source)) => source,
+        Err(e) => {
+            eprintln!("Failed to find a session: {}", e);
+            process::exit(1);
+        }
+    };
+    println!("Using the session of the {} account from {}.", name, source);
+
+    let client = match Client::from_env(account.as_deref()) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up the client: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match client.validate() {
+        Ok(user) if user.is_empty() => println!("🎄 The session is valid."),
+        Ok(user) => println!("🎄 The session is valid, logged in as {}.", user),
+        Err(e) => {
+            eprintln!("Failed to validate the session: {}", e);
+            if env::var("AOC_BASE_URL").is_err() {
+                eprintln!(
+                    "Log in at {} and copy a fresh `session` cookie.",
+                    DEFAULT_BASE_URL
+                );
+            }
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+use advent_of_code_tools::{
+    aoc::Client,
+    current_year,
+    input::write_atomic,
+    leaderboard::{readme_path, render_table, update_readme, Leaderboard, TABLE_MARKER},
+};
+
+struct Args {
+    year: i16,
+    /// The member whose stars are shown, `AOC_USER_ID` if not passed.
+    user: Option<u64>,
+    /// Defaults to the private leaderboard of `user`.
+    leaderboard: Option<u64>,
+    /// Read a saved leaderboard instead of fetching it.
+    file: Option<PathBuf>,
+    account: Option<String>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(current_year),
+        user: args.opt_value_from_str("--user")?,
+        leaderboard: args.opt_value_from_str("--leaderboard")?,
+        file: args.opt_value_from_str("--file")?,
+        account: args.opt_value_from_str("--account")?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let user = match args
+        .user
+        .or_else(|| env::var("AOC_USER_ID").ok()?.trim().parse().ok())
+    {
+        Some(user) => user,
+        None => {
+            eprintln!("Need to specify a user id with `--user <id>` or AOC_USER_ID. It is shown on https://adventofcode.com/settings.");
+            process::exit(1);
+        }
+    };
+
+    let json = match &args.file {
+        Some(path) => fs::read_to_string(path).map_err(|e| e.to_string()),
+        None => Client::from_env(args.account.as_deref())
+            .and_then(|client| client.leaderboard(args.year, args.leaderboard.unwrap_or(user)))
+            .map_err(|e| e.to_string()),
+    };
+    let leaderboard = match json.map(|json| Leaderboard::from_json(&json)) {
+        Ok(Ok(leaderboard)) => leaderboard,
+        Ok(Err(e)) => {
+            eprintln!("Failed to parse the leaderboard: {}", e);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to load the leaderboard: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let member = match leaderboard.member(user) {
+        Some(member) => member,
+        None => {
+            eprintln!(
+                "User {} is not a member of the {} leaderboard.",
+                user, leaderboard.event
+            );
+            process::exit(1);
+        }
+    };
+
+    let path = readme_path();
+    let readme = match fs::read_to_string(&path) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    };
+    let updated = match update_readme(&readme, &render_table(args.year, member)) {
+        Some(updated) => updated,
+        None => {
+            eprintln!(
+                "\"{}\" does not contain the `{}` line.",
+                path.display(),
+                TABLE_MARKER
+            );
+            process::exit(1);
+        }
+    };
+
+    match write_atomic(&path, &updated) {
+        Ok(_) => println!(
+            "🎄 Updated \"{}\" with the {} star(s) of {}.",
+            path.display(),
+            member.stars,
+            member
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("anonymous user #{}", member.id))
+        ),
+        Err(e) => {
+            eprintln!("Failed to write \"{}\": {}", path.display(), e);
+            process::exit(1);
+        }
+    }
+}
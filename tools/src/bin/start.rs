@@ -0,0 +1,130 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::Path;
+use std::{fs, process};
+
+use advent_of_code_tools::{
+    current_year, input::project_root, input_path, puzzle::puzzle_path, unlock,
+};
+use chrono::Utc;
+
+struct Args {
+    /// The puzzle that unlocked today if not passed.
+    day: Option<u8>,
+    year: Option<i16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        day: args.opt_free_from_str()?,
+    })
+}
+
+/// Runs another command of this template, e.g. `cargo download`, with its output shown.
+/// A failing command reports its own error, so only failing to start it is reported here.
+fn cargo(bin: &str, args: &[String]) {
+    println!("> cargo {} {}", bin, args.join(" "));
+    if let Err(e) = process::Command::new("cargo")
+        .args(["run", "--quiet", "--bin", bin, "--"])
+        .args(args)
+        .current_dir(project_root())
+        .status()
+    {
+        eprintln!("Failed to spawn cargo: {}", e);
+    }
+}
+
+fn has_contents(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Whether the saved description already includes part two.
+fn has_part_two(path: &Path) -> bool {
+    fs::read_to_string(path).is_ok_and(|markdown| markdown.contains("## Part Two"))
+}
+
+fn check(done: bool, label: &str, path: &Path) {
+    let mark = if done { "✅" } else { "❌" };
+    println!("{} {} \"{}\"", mark, label, path.display());
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let (year, day) = match (args.day, args.year) {
+        (Some(day), year) => (year.unwrap_or_else(current_year), day),
+        (None, year) => match unlock::current_day(Utc::now()) {
+            Some((current, day)) if year.is_none_or(|year| year == current) => (current, day),
+            _ => {
+                eprintln!(
+                    "No puzzle unlocked today. Need to specify a day. example: `cargo start 7`"
+                );
+                process::exit(1);
+            }
+        },
+    };
+    let day_args = vec![day.to_string(), "--year".to_owned(), year.to_string()];
+
+    let module_path = project_root().join(format!("src/years/y{}/day{:02}.rs", year, day));
+    let input = input_path("inputs", year, day);
+    let puzzle = puzzle_path(year, day);
+    let example = input_path("examples", year, day);
+
+    // steps whose result exists are skipped, and results are kept if a later step fails.
+    if !module_path.exists() {
+        cargo("scaffold", &day_args);
+    }
+
+    let missing_input = !has_contents(&input);
+    let missing_puzzle = !has_part_two(&puzzle);
+    if missing_input || missing_puzzle {
+        let mut download_args = day_args.clone();
+        if missing_puzzle {
+            download_args.push("--puzzle".to_owned());
+        }
+        cargo("download", &download_args);
+    }
+
+    if !has_contents(&example) && puzzle.exists() && module_path.exists() {
+        cargo("example", &day_args);
+    }
+
+    let parts = match (puzzle.exists(), has_part_two(&puzzle)) {
+        (true, true) => "Description of both parts",
+        (true, false) => "Description of part one",
+        (false, _) => "Description",
+    };
+    let ready = [
+        (module_path.exists(), "Solution", &module_path),
+        (has_contents(&input), "Input", &input),
+        (puzzle.exists(), parts, &puzzle),
+        (has_contents(&example), "Example", &example),
+    ];
+
+    println!("---");
+    for (done, label, path) in &ready {
+        check(*done, label, path);
+    }
+    if ready.iter().all(|(done, _, _)| *done) {
+        println!(
+            "🎄 Day {} of {} is ready. Type `cargo test --bin {:02}` to check your solution against the example.",
+            day, year, day
+        );
+    } else {
+        println!(
+            "Run `cargo start {} --year {}` again to fill in what is missing.",
+            day, year
+        );
+        process::exit(1);
+    }
+}
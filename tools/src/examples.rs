@@ -0,0 +1,164 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Examples of a puzzle and the answers they are expected to give.
+//!
+//! Each example sits next to its expectations: `src/examples/<year>/<day>.txt` next to
+//! `<day>.toml`, and numbered examples, for puzzles with more than one, `<day>-<n>.txt` next to
+//! `<day>-<n>.toml`:
+//!
+//! ```toml
+//! # src/examples/2022/06-1.toml
+//! part_one = 5
+//! part_two = "23"
+//! ```
+//!
+//! Either part may be left out, e.g. for examples that only apply to part two. The `build.rs` of
+//! `advent_of_code` turns every expectation into a test.
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::{fs, io};
+
+use crate::input::project_root;
+
+/// `src/examples/<year>/<day>.<extension>`, or `<day>-<index>.<extension>` for numbered examples.
+fn path(year: i16, day: u8, index: Option<u8>, extension: &str) -> PathBuf {
+    let stem = match index {
+        Some(index) => format!("{:02}-{}", day, index),
+        None => format!("{:02}", day),
+    };
+    project_root()
+        .join("src")
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("{}.{}", stem, extension))
+}
+
+pub fn example_path(year: i16, day: u8, index: Option<u8>) -> PathBuf {
+    path(year, day, index, "txt")
+}
+
+pub fn expectations_path(year: i16, day: u8, index: Option<u8>) -> PathBuf {
+    path(year, day, index, "toml")
+}
+
+#[derive(Debug)]
+pub enum ExpectationsError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    /// The file is valid TOML but does not follow the `part_one = answer` layout.
+    Layout(String),
+}
+
+impl Display for ExpectationsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectationsError::Io(e) => write!(f, "could not read expectations: {}", e),
+            ExpectationsError::Parse(e) => write!(f, "could not parse expectations: {}", e),
+            ExpectationsError::Layout(e) => write!(f, "unexpected entry in expectations: {}", e),
+        }
+    }
+}
+
+/// The answers an example is expected to give.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expectations {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Expectations {
+    /// Reads the expectations of an example. A missing file expects nothing.
+    pub fn load(year: i16, day: u8, index: Option<u8>) -> Result<Expectations, ExpectationsError> {
+        match fs::read_to_string(expectations_path(year, day, index)) {
+            Ok(contents) => Expectations::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expectations::default()),
+            Err(e) => Err(ExpectationsError::Io(e)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Expectations, ExpectationsError> {
+        let table: toml::value::Table =
+            toml::from_str(contents).map_err(ExpectationsError::Parse)?;
+        let mut expectations = Expectations::default();
+
+        for (key, answer) in &table {
+            let answer = match answer {
+                toml::Value::String(answer) => answer.to_owned(),
+                toml::Value::Integer(answer) => answer.to_string(),
+                other => {
+                    return Err(ExpectationsError::Layout(format!(
+                        "{} is a {}",
+                        key,
+                        other.type_str()
+                    )))
+                }
+            };
+            match key.as_str() {
+                "part_one" => expectations.part_one = Some(answer),
+                "part_two" => expectations.part_two = Some(answer),
+                other => {
+                    return Err(ExpectationsError::Layout(format!(
+                        "\"{}\" is not `part_one` or `part_two`",
+                        other
+                    )))
+                }
+            }
+        }
+
+        Ok(expectations)
+    }
+
+    /// Numbers are written as integers, anything else as strings.
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        for (key, answer) in [("part_one", &self.part_one), ("part_two", &self.part_two)] {
+            if let Some(answer) = answer {
+                match answer.parse::<i64>() {
+                    Ok(number) => toml.push_str(&format!("{} = {}\n", key, number)),
+                    Err(_) => toml.push_str(&format!("{} = {:?}\n", key, answer)),
+                }
+            }
+        }
+        toml
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths() {
+        assert!(example_path(2022, 6, None).ends_with("src/examples/2022/06.txt"));
+        assert!(example_path(2022, 6, Some(2)).ends_with("src/examples/2022/06-2.txt"));
+        assert!(expectations_path(2022, 6, Some(2)).ends_with("src/examples/2022/06-2.toml"));
+    }
+
+    #[test]
+    fn test_parse() {
+        let expectations = Expectations::parse("part_one = 5\npart_two = \"CMZ\"\n").unwrap();
+        assert_eq!(expectations.get(1), Some("5"));
+        assert_eq!(expectations.get(2), Some("CMZ"));
+        assert_eq!(
+            Expectations::parse(&expectations.to_toml()).unwrap(),
+            expectations
+        );
+
+        let only_two = Expectations::parse("part_two = 23\n").unwrap();
+        assert_eq!(only_two.get(1), None);
+        assert!(matches!(
+            Expectations::parse("part_three = 1\n"),
+            Err(ExpectationsError::Layout(_))
+        ));
+    }
+}
@@ -0,0 +1,190 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{env, fs, process};
+
+/// Where a solution reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/<folder>/<year>/<day>.txt`, e.g. the downloaded input or the example.
+    Puzzle {
+        folder: String,
+        year: i16,
+        day: u8,
+    },
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    pub fn puzzle(folder: &str, year: i16, day: u8) -> Self {
+        InputSource::Puzzle {
+            folder: folder.to_owned(),
+            year,
+            day,
+        }
+    }
+
+    /// Parses the value of `--input`: `-` reads from stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Puzzle { folder, year, day } => {
+                read_path(&input_path(folder, *year, *day))
+            }
+            InputSource::Path(path) => read_path(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(InputError::Stdin)?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "no input at \"{}\"", path.display()),
+            InputError::Io(path, e) => {
+                write!(f, "could not read input \"{}\": {}", path.display(), e)
+            }
+            InputError::Stdin(e) => write!(f, "could not read input from stdin: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn read_path(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_owned()),
+        _ => InputError::Io(path.to_owned(), e),
+    })
+}
+
+/// The directory containing the project's `Cargo.toml`, independent of the working directory.
+/// This crate lives in its `tools/` directory.
+pub fn project_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("`tools/` is inside the project")
+        .to_path_buf()
+}
+
+pub fn input_path(folder: &str, year: i16, day: u8) -> PathBuf {
+    project_root()
+        .join("src")
+        .join(folder)
+        .join(year.to_string())
+        .join(format!("{:02}.txt", day))
+}
+
+/// `"inputs"`, or `"inputs/<account>"` for the inputs of a named account.
+pub fn inputs_folder(account: Option<&str>) -> String {
+    match account {
+        Some(account) => format!("inputs/{}", account),
+        None => "inputs".to_owned(),
+    }
+}
+
+pub fn read_file(folder: &str, year: i16, day: u8) -> Result<String, InputError> {
+    InputSource::puzzle(folder, year, day).read()
+}
+
+/// Replaces `path` by renaming a fully written temporary file over it, so readers never see a
+/// partial file. Parent directories are created as needed.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent)?;
+
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = parent.join(format!(
+        ".{}.{}-{}.tmp",
+        file_name,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let written = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|_| fs::rename(&tmp_path, path));
+
+    if written.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    written
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("big.txt"),
+            InputSource::Path(PathBuf::from("big.txt"))
+        );
+    }
+
+    #[test]
+    fn test_input_path_ignores_working_directory() {
+        let path = input_path("examples", 2022, 5);
+        assert!(path.is_absolute());
+        assert!(path.ends_with("src/examples/2022/05.txt"));
+    }
+
+    #[test]
+    fn test_missing_input_is_reported() {
+        let error = InputSource::Path(PathBuf::from("does/not/exist.txt"))
+            .read()
+            .unwrap_err();
+        assert!(matches!(error, InputError::NotFound(_)));
+        assert_eq!(error.to_string(), "no input at \"does/not/exist.txt\"");
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = env::temp_dir().join(format!("aoc-write-atomic-{}", process::id()));
+        let path = dir.join("2022").join("01.txt");
+
+        write_atomic(&path, "first").unwrap();
+        write_atomic(&path, "second").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_eq!(fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
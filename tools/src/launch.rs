@@ -0,0 +1,267 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Adds and removes the VS Code debug configurations of a day in `.vscode/launch.json`.
+//!
+//! The file may contain comments and is edited as text, so everything else in it, including
+//! formatting and comments, stays as the user left it.
+use std::ops::Range;
+
+use regex::Regex;
+
+pub const LAUNCH_PATH: &str = ".vscode/launch.json";
+
+/// Names of the configurations of a day: running its solution and running its unit tests, both
+/// through the day's binary, `src/bin/<day>.rs`.
+pub fn names(year: i16, day: u8) -> [String; 2] {
+    [
+        format!("Debug day {:02} ({})", day, year),
+        format!("Debug unit tests day {:02} ({})", day, year),
+    ]
+}
+
+fn entries(year: i16, day: u8) -> String {
+    let [solve, tests] = names(year, day);
+    format!(
+        r#"        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "{solve}",
+            "cargo": {{
+                "args": ["build", "--bin={day:02}", "--package=advent_of_code"],
+                "filter": {{
+                    "name": "{day:02}",
+                    "kind": "bin"
+                }}
+            }},
+            "args": ["--year", "{year}"],
+            "cwd": "${{workspaceFolder}}"
+        }},
+        {{
+            "type": "lldb",
+            "request": "launch",
+            "name": "{tests}",
+            "cargo": {{
+                "args": ["test", "--no-run", "--bin={day:02}", "--package=advent_of_code"],
+                "filter": {{
+                    "name": "{day:02}",
+                    "kind": "bin"
+                }}
+            }},
+            "args": ["y{year}::day{day:02}::"],
+            "cwd": "${{workspaceFolder}}"
+        }}"#
+    )
+}
+
+/// Where the `configurations` array is.
+struct Configurations {
+    /// Position of its `[`.
+    start: usize,
+    /// Each configuration object, from `{` to `}`.
+    objects: Vec<Range<usize>>,
+}
+
+/// Finds the `configurations` array of the root object, skipping strings and comments.
+fn configurations(json: &str) -> Option<Configurations> {
+    let bytes = json.as_bytes();
+    let mut depth = 0;
+    let mut key_seen = false;
+    let mut found: Option<Configurations> = None;
+    let mut object_start = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let start = i + 1;
+                i += 1;
+                while i < bytes.len() && bytes[i] != b'"' {
+                    i += if bytes[i] == b'\\' { 2 } else { 1 };
+                }
+                if depth == 1 && json.get(start..i) == Some("configurations") {
+                    key_seen = true;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'/') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = json[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 1);
+            }
+            b',' if depth == 1 => key_seen = false,
+            b'[' | b'{' => {
+                depth += 1;
+                if bytes[i] == b'[' && depth == 2 && key_seen && found.is_none() {
+                    found = Some(Configurations {
+                        start: i,
+                        objects: vec![],
+                    });
+                } else if bytes[i] == b'{' && depth == 3 {
+                    object_start = i;
+                }
+            }
+            b']' | b'}' => {
+                if depth == 3 && bytes[i] == b'}' {
+                    if let Some(found) = found.as_mut().filter(|_| key_seen) {
+                        found.objects.push(object_start..i + 1);
+                    }
+                }
+                if depth == 2 && bytes[i] == b']' && key_seen {
+                    return found;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+fn has_name(object: &str, name: &str) -> bool {
+    Regex::new(&format!(r#""name"\s*:\s*"{}""#, regex::escape(name)))
+        .unwrap()
+        .is_match(object)
+}
+
+/// Appends the configurations of a day. Days that already have them are left alone.
+/// `None` if there is no `configurations` array.
+pub fn add_day(json: &str, year: i16, day: u8) -> Option<String> {
+    let configurations = configurations(json)?;
+    let [solve, _] = names(year, day);
+    if configurations
+        .objects
+        .iter()
+        .any(|object| has_name(&json[object.clone()], &solve))
+    {
+        return Some(json.to_owned());
+    }
+
+    let mut updated = json.to_owned();
+    match configurations.objects.last() {
+        Some(last) => updated.insert_str(last.end, &format!(",\n{}", entries(year, day))),
+        None => updated.insert_str(
+            configurations.start + 1,
+            &format!("\n{}\n    ", entries(year, day)),
+        ),
+    }
+    Some(updated)
+}
+
+/// Removes the configurations of a day, along with the comma separating them from the rest.
+/// `None` if there is no `configurations` array.
+pub fn remove_day(json: &str, year: i16, day: u8) -> Option<String> {
+    let names = names(year, day);
+    let mut updated = json.to_owned();
+
+    // one at a time, as each removal moves the objects after it.
+    loop {
+        let configurations = configurations(&updated)?;
+        let Some((index, object)) = configurations
+            .objects
+            .iter()
+            .enumerate()
+            .find(|(_, object)| {
+                names
+                    .iter()
+                    .any(|name| has_name(&updated[(*object).clone()], name))
+            })
+            .map(|(index, object)| (index, object.clone()))
+        else {
+            return Some(updated);
+        };
+
+        let next = |from: usize| {
+            updated[from..]
+                .find(|c: char| !c.is_whitespace())
+                .map_or(updated.len(), |offset| from + offset)
+        };
+        // takes the comma before the object, or after it if it is the first one.
+        let range = if index > 0 {
+            let before = updated[..object.start].rfind(',').unwrap_or(object.start);
+            before..object.end
+        } else if updated.as_bytes().get(next(object.end)) == Some(&b',') {
+            object.start..next(next(object.end) + 1)
+        } else {
+            // the only object, which leaves an empty array.
+            configurations.start + 1..next(object.end)
+        };
+        updated.replace_range(range, "");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAUNCH: &str = r#"{
+    // Use IntelliSense to learn about possible attributes.
+    "version": "0.2.0",
+    "configurations": [
+        {
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug executable 'advent_of_code'",
+            "cargo": {
+                "args": ["build", "--bin=advent_of_code", "--package=advent_of_code"],
+                "filter": {
+                    "name": "advent_of_code",
+                    "kind": "bin"
+                }
+            },
+            "args": ["all"],
+            "cwd": "${workspaceFolder}"
+        },
+        /* "configurations": [] in a comment is skipped. */
+        {
+            "type": "lldb",
+            "request": "launch",
+            "name": "Debug unit tests in library 'advent_of_code'",
+            "cargo": {
+                "args": ["test", "--no-run", "--lib", "--package=advent_of_code"],
+                "filter": {
+                    "name": "advent_of_code",
+                    "kind": "lib"
+                }
+            },
+            "args": [],
+            "cwd": "${workspaceFolder}"
+        }
+    ]
+}
+"#;
+
+    #[test]
+    fn test_add_and_remove_day() {
+        let added = add_day(LAUNCH, 2022, 5).unwrap();
+        assert_eq!(configurations(&added).unwrap().objects.len(), 4);
+        assert!(added.contains(r#""args": ["build", "--bin=05", "--package=advent_of_code"]"#));
+        assert!(added.contains(r#""args": ["--year", "2022"]"#));
+        assert!(added.contains(r#""args": ["y2022::day05::"]"#));
+        assert!(added.starts_with("{\n    // Use IntelliSense"));
+        assert_eq!(add_day(&added, 2022, 5).unwrap(), added);
+
+        let both = add_day(&added, 2022, 6).unwrap();
+        assert_eq!(remove_day(&both, 2022, 6).unwrap(), added);
+        assert_eq!(
+            remove_day(&remove_day(&both, 2022, 5).unwrap(), 2022, 6).unwrap(),
+            LAUNCH
+        );
+        assert_eq!(remove_day(&added, 2022, 5).unwrap(), LAUNCH);
+    }
+
+    #[test]
+    fn test_empty_configurations() {
+        let json = "{\n    \"version\": \"0.2.0\",\n    \"configurations\": []\n}\n";
+        let added = add_day(json, 2022, 1).unwrap();
+        assert_eq!(configurations(&added).unwrap().objects.len(), 2);
+        assert_eq!(remove_day(&added, 2022, 1).unwrap(), json);
+        assert!(add_day("{ \"version\": \"0.2.0\" }", 2022, 1).is_none());
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::{aoc::DEFAULT_BASE_URL, input::project_root, unlock::days_in_event};
+
+/// The line in `README.md` the star table is written after.
+pub const TABLE_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+pub fn readme_path() -> PathBuf {
+    project_root().join("README.md")
+}
+
+/// The JSON served at `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    /// Keyed by member id.
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    /// Day, then part, to when its star was collected.
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Leaderboard, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn member(&self, id: u64) -> Option<&Member> {
+        self.members.values().find(|member| member.id == id)
+    }
+}
+
+impl Member {
+    pub fn has_star(&self, day: u8, part: u8) -> bool {
+        self.completion_day_level
+            .get(&day.to_string())
+            .is_some_and(|parts| parts.contains_key(&part.to_string()))
+    }
+}
+
+/// The table `k2bd/advent-readme-stars` renders: one row per day with at least one star.
+pub fn render_table(year: i16, member: &Member) -> String {
+    let mut table = format!(
+        "{}\n## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        TABLE_MARKER, year
+    );
+    for day in (1..=days_in_event(year)).filter(|&day| member.has_star(day, 1)) {
+        let star = |part| {
+            if member.has_star(day, part) {
+                "⭐"
+            } else {
+                " "
+            }
+        };
+        table.push_str(&format!(
+            "| [Day {1}]({0}/{2}/day/{1}) | {3} | {4} |\n",
+            DEFAULT_BASE_URL,
+            day,
+            year,
+            star(1),
+            star(2)
+        ));
+    }
+    table
+}
+
+/// Replaces the table after [`TABLE_MARKER`], or inserts it if there is none yet.
+/// `None` if the marker is missing.
+pub fn update_readme(readme: &str, table: &str) -> Option<String> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let start = lines.iter().position(|line| line.trim() == TABLE_MARKER)?;
+
+    // skips the heading, blank lines around it and the rows of a previous table.
+    let mut end = start + 1;
+    if lines
+        .get(end)
+        .is_some_and(|line| line.starts_with('#') && line.ends_with(" Results"))
+    {
+        end += 1;
+        while lines.get(end).is_some_and(|line| line.trim().is_empty()) {
+            end += 1;
+        }
+        while lines.get(end).is_some_and(|line| line.starts_with('|')) {
+            end += 1;
+        }
+    }
+
+    let mut updated: Vec<&str> = lines[..start].to_vec();
+    updated.extend(table.lines());
+    updated.extend(&lines[end..]);
+    Some(updated.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str =
+        "# 🎄 Advent of Code\n\n<!--- advent_readme_stars table --->\n\n---\n\n## Usage\n";
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::from_json(include_str!("fixtures/leaderboard.json")).unwrap()
+    }
+
+    #[test]
+    fn test_member() {
+        let leaderboard = leaderboard();
+        let member = leaderboard.member(1234567).unwrap();
+        assert_eq!(member.name.as_deref(), Some("ferris"));
+        assert!(member.has_star(2, 2));
+        assert!(!member.has_star(3, 2));
+        assert!(leaderboard.member(42).is_none());
+    }
+
+    #[test]
+    fn test_render_table() {
+        let leaderboard = leaderboard();
+        assert_eq!(
+            render_table(2022, leaderboard.member(1234567).unwrap()),
+            include_str!("fixtures/leaderboard_table.md")
+        );
+    }
+
+    #[test]
+    fn test_update_readme_in_place() {
+        let table = render_table(2022, leaderboard().member(1234567).unwrap());
+        let updated = update_readme(README, &table).unwrap();
+        assert_eq!(
+            updated,
+            format!("# 🎄 Advent of Code\n\n{}\n---\n\n## Usage\n", table)
+        );
+        // a second run replaces the table instead of adding another one.
+        assert_eq!(update_readme(&updated, &table).unwrap(), updated);
+        assert_eq!(update_readme("# No marker\n", &table), None);
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! Everything the commands in `./bin/` need, kept apart from the solutions: a solution that does
+//! not compile yet must not keep you from scaffolding or downloading the next day.
+//! `advent_of_code` re-exports these modules.
+use std::env;
+
+use chrono::{Datelike, NaiveDate, Utc};
+
+pub mod aoc;
+pub mod examples;
+pub mod input;
+pub mod launch;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod submit;
+pub mod unlock;
+
+pub use input::{input_path, read_file};
+
+/// The year commands use unless `--year` is passed: `AOC_YEAR` if it is set,
+/// otherwise the most recent event.
+pub fn current_year() -> i16 {
+    if let Some(year) = env::var("AOC_YEAR").ok().and_then(|year| year.parse().ok()) {
+        return year;
+    }
+
+    latest_event(unlock::today(Utc::now()))
+}
+
+/// Events run in December, so until then the previous year's event is the latest one.
+fn latest_event(today: NaiveDate) -> i16 {
+    let year = today.year() as i16;
+    if today.month() == 12 {
+        year
+    } else {
+        year - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_event() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(latest_event(date(2022, 11, 30)), 2021);
+        assert_eq!(latest_event(date(2022, 12, 1)), 2022);
+        assert_eq!(latest_event(date(2023, 1, 15)), 2022);
+    }
+}
@@ -0,0 +1,306 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{aoc::DEFAULT_BASE_URL, input::project_root};
+
+/// `src/puzzles/<year>/<day>.md`, where `cargo download --puzzle` saves the description.
+pub fn puzzle_path(year: i16, day: u8) -> PathBuf {
+    project_root()
+        .join("src")
+        .join("puzzles")
+        .join(year.to_string())
+        .join(format!("{:02}.md", day))
+}
+
+lazy_static! {
+    static ref ARTICLE: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref TOKEN: Regex = Regex::new(r"(?s)<(/?)([a-zA-Z0-9]+)([^>]*)>|([^<]+)").unwrap();
+    static ref HREF: Regex = Regex::new(r#"href="([^"]*)""#).unwrap();
+    static ref HEADING: Regex = Regex::new(r"^-+\s*(.*?)\s*-+$").unwrap();
+}
+
+/// Marks `<em>` inside of `<code>` until the code span is complete.
+const EM_START: char = '\u{1}';
+const EM_END: char = '\u{2}';
+
+/// The description of each unlocked part, as HTML.
+pub fn articles(html: &str) -> Vec<&str> {
+    ARTICLE
+        .captures_iter(html)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect()
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Converts the descriptions of a puzzle page to Markdown.
+/// Only the handful of tags used on puzzle pages are understood, anything else is dropped.
+pub fn to_markdown(html: &str) -> String {
+    let parts: Vec<String> = articles(html)
+        .into_iter()
+        .map(article_to_markdown)
+        .collect();
+    parts.join("\n\n") + "\n"
+}
+
+fn article_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    // text of the innermost `<code>`, `<pre>` or `<a>` that is being collected.
+    let mut pre: Option<String> = None;
+    let mut code: Option<String> = None;
+    let mut link: Option<(String, String)> = None;
+    let mut heading = false;
+
+    fn push_text(
+        text: &str,
+        out: &mut String,
+        code: &mut Option<String>,
+        link: &mut Option<(String, String)>,
+    ) {
+        match (code, link) {
+            (Some(code), _) => code.push_str(text),
+            (None, Some((_, link))) => link.push_str(text),
+            (None, None) => out.push_str(text),
+        }
+    }
+
+    for token in TOKEN.captures_iter(html) {
+        if let Some(text) = token.get(4) {
+            let text = decode_entities(text.as_str());
+            match pre.as_mut() {
+                Some(pre) => pre.push_str(&text),
+                None => {
+                    // whitespace is insignificant outside of `<pre>`.
+                    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if text.starts_with(char::is_whitespace) && !collapsed.is_empty() {
+                        collapsed.insert(0, ' ');
+                    }
+                    if text.ends_with(char::is_whitespace) {
+                        collapsed.push(' ');
+                    }
+                    if !collapsed.trim().is_empty() || !out.ends_with([' ', '\n']) {
+                        push_text(&collapsed, &mut out, &mut code, &mut link);
+                    }
+                }
+            }
+            continue;
+        }
+
+        let closing = !token[1].is_empty();
+        let attributes = &token[3];
+        match (token[2].to_ascii_lowercase().as_str(), closing) {
+            ("pre", false) => pre = Some(String::new()),
+            ("pre", true) => {
+                if let Some(pre) = pre.take() {
+                    out.push_str("```\n");
+                    out.push_str(pre.trim_end_matches('\n'));
+                    out.push_str("\n```\n\n");
+                }
+            }
+            (_, _) if pre.is_some() => {}
+            ("code", false) => code = Some(String::new()),
+            ("code", true) => {
+                if let Some(text) = code.take() {
+                    // code spans cannot contain emphasis, so it is only kept if it covers the
+                    // whole span, e.g. `<code><em>3000</em></code>`.
+                    let emphasized = text.starts_with(EM_START)
+                        && text.ends_with(EM_END)
+                        && text.matches(EM_START).count() == 1;
+                    let text = text.replace([EM_START, EM_END], "");
+                    let ticks = if text.contains('`') { "``" } else { "`" };
+                    let code = format!("{0}{1}{0}", ticks, text);
+                    let code = if emphasized {
+                        format!("*{}*", code)
+                    } else {
+                        code
+                    };
+                    push_text(&code, &mut out, &mut None, &mut link);
+                }
+            }
+            ("em", closing) if code.is_some() => {
+                if let Some(code) = code.as_mut() {
+                    code.push(if closing { EM_END } else { EM_START });
+                }
+            }
+            ("em", _) => push_text("*", &mut out, &mut code, &mut link),
+            ("a", false) => {
+                let href = HREF
+                    .captures(attributes)
+                    .map(|captures| decode_entities(&captures[1]))
+                    .unwrap_or_default();
+                let href = if href.starts_with('/') {
+                    format!("{}{}", DEFAULT_BASE_URL, href)
+                } else {
+                    href
+                };
+                link = Some((href, String::new()));
+            }
+            ("a", true) => {
+                if let Some((href, text)) = link.take() {
+                    out.push_str(&format!("[{}]({})", text, href));
+                }
+            }
+            ("h2", false) => {
+                heading = true;
+                out.push_str("## ");
+            }
+            ("h2", true) if heading => {
+                // `--- Day 1: Calorie Counting ---` becomes `Day 1: Calorie Counting`.
+                let start = out.rfind("## ").unwrap() + 3;
+                let title = out[start..].trim().to_owned();
+                let title = HEADING
+                    .captures(&title)
+                    .map(|captures| captures[1].to_owned())
+                    .unwrap_or(title);
+                out.truncate(start);
+                out.push_str(&title);
+                out.push_str("\n\n");
+                heading = false;
+            }
+            ("li", false) => out.push_str("- "),
+            ("li", true) => {
+                out.truncate(out.trim_end().len());
+                out.push('\n');
+            }
+            ("ul", true) => out.push('\n'),
+            ("p", true) => {
+                out.truncate(out.trim_end().len());
+                out.push_str("\n\n");
+            }
+            _ => {}
+        }
+    }
+
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    let mut markdown = lines.join("\n");
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.trim().to_owned()
+}
+
+/// The title from the first heading of a saved description, e.g. `Calorie Counting` for
+/// `## Day 1: Calorie Counting`.
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find_map(|line| line.strip_prefix("## "))?;
+    let (_, title) = heading.split_once(": ")?;
+    Some(title.trim().to_owned())
+}
+
+/// An example input and the answers the puzzle text gives for it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Example {
+    pub input: Option<String>,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+lazy_static! {
+    static ref CODE_BLOCK: Regex = Regex::new(r"(?s)```\n(.*?)\n```").unwrap();
+    static ref HIGHLIGHTED: Regex = Regex::new(r"\*`([^`]+)`\*").unwrap();
+}
+
+/// Guesses the example from a description saved by `cargo download --puzzle`.
+///
+/// The input is the first code block spanning several lines, or the code block at `block`
+/// (counting from `0`) if given. The answer to each part is the last highlighted code span in
+/// its description, which is where the puzzle text usually states it.
+pub fn extract_example(markdown: &str, block: Option<usize>) -> Example {
+    let blocks: Vec<&str> = CODE_BLOCK
+        .captures_iter(markdown)
+        .map(|captures| captures.get(1).unwrap().as_str())
+        .collect();
+    let input = match block {
+        Some(block) => blocks.get(block).copied(),
+        None => blocks
+            .iter()
+            .find(|block| block.lines().count() > 1)
+            .or_else(|| blocks.first())
+            .copied(),
+    };
+
+    let (part_one, part_two) = match markdown.find("## Part Two") {
+        Some(split) => (&markdown[..split], Some(&markdown[split..])),
+        None => (markdown, None),
+    };
+    let answer = |text: &str| {
+        HIGHLIGHTED
+            .captures_iter(text)
+            .last()
+            .map(|captures| captures[1].to_owned())
+    };
+
+    Example {
+        input: input.map(|input| format!("{}\n", input)),
+        part_one: answer(part_one),
+        part_two: part_two.and_then(answer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_markdown_part_one() {
+        assert_eq!(
+            to_markdown(include_str!("fixtures/puzzle_part_one.html")),
+            include_str!("fixtures/puzzle_part_one.md")
+        );
+    }
+
+    #[test]
+    fn test_to_markdown_part_two() {
+        assert_eq!(
+            to_markdown(include_str!("fixtures/puzzle_part_two.html")),
+            include_str!("fixtures/puzzle_part_two.md")
+        );
+    }
+
+    #[test]
+    fn test_articles() {
+        assert_eq!(
+            articles(include_str!("fixtures/puzzle_part_one.html")).len(),
+            1
+        );
+        assert_eq!(
+            articles(include_str!("fixtures/puzzle_part_two.html")).len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            title(include_str!("fixtures/puzzle_part_one.md")).as_deref(),
+            Some("Counting Snacks")
+        );
+        assert_eq!(title("No heading here."), None);
+    }
+
+    #[test]
+    fn test_extract_example() {
+        let example = extract_example(include_str!("fixtures/puzzle_part_two.md"), None);
+        assert_eq!(example.input.as_deref(), Some("1000\n2000\n\n3000\n"));
+        assert_eq!(example.part_one.as_deref(), Some("3000"));
+        assert_eq!(example.part_two.as_deref(), Some("45000"));
+
+        let example = extract_example(include_str!("fixtures/puzzle_part_one.md"), Some(1));
+        assert_eq!(example.input, None);
+        assert_eq!(example.part_two, None);
+    }
+}
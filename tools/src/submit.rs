@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::input::project_root;
+
+/// Every submitted answer is appended to this file, one JSON object per line.
+pub const SUBMISSIONS_PATH: &str = "submissions.jsonl";
+
+/// How the website classified a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Submission {
+    Correct,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently. Nothing was checked.
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part was solved before, or part two is not unlocked yet.
+    AlreadySolved,
+    /// The response did not match any known message. Holds its text.
+    Unknown {
+        message: String,
+    },
+}
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref WAIT: Regex = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+impl Submission {
+    /// Classifies the page returned after posting an answer.
+    pub fn from_html(html: &str) -> Submission {
+        let message = ARTICLE
+            .captures(html)
+            .map(|captures| TAG.replace_all(&captures[1], "").into_owned())
+            .unwrap_or_else(|| TAG.replace_all(html, "").into_owned());
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Submission::Correct
+        } else if message.contains("You gave an answer too recently") {
+            let wait_secs = WAIT
+                .captures(&message)
+                .map(|captures| {
+                    let minutes = captures
+                        .get(1)
+                        .map_or(0, |minutes| minutes.as_str().parse().unwrap_or(0));
+                    let seconds: u64 = captures[2].parse().unwrap_or(0);
+                    minutes * 60 + seconds
+                })
+                .unwrap_or(60);
+            Submission::RateLimited { wait_secs }
+        } else if message.contains("Did you already complete it") {
+            Submission::AlreadySolved
+        } else if message.contains("your answer is too high") {
+            Submission::TooHigh
+        } else if message.contains("your answer is too low") {
+            Submission::TooLow
+        } else if message.contains("That's not the right answer") {
+            Submission::Wrong
+        } else {
+            Submission::Unknown { message }
+        }
+    }
+
+    pub fn wait(&self) -> Option<Duration> {
+        match self {
+            Submission::RateLimited { wait_secs } => Some(Duration::from_secs(*wait_secs)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Submission::Correct => write!(f, "✅ That's the right answer!"),
+            Submission::Wrong => write!(f, "❌ That's not the right answer."),
+            Submission::TooHigh => write!(f, "❌ That's not the right answer, it is too high."),
+            Submission::TooLow => write!(f, "❌ That's not the right answer, it is too low."),
+            Submission::RateLimited { wait_secs } => write!(
+                f,
+                "⏳ You gave an answer too recently, wait {}s before trying again.",
+                wait_secs
+            ),
+            Submission::AlreadySolved => {
+                write!(f, "⭐ This part is already solved, or not unlocked yet.")
+            }
+            Submission::Unknown { message } => write!(f, "❔ Unexpected response: {}", message),
+        }
+    }
+}
+
+/// One line of [`SUBMISSIONS_PATH`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: i16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    /// The named account the answer was submitted for, `None` for the default one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub account: Option<String>,
+    #[serde(flatten)]
+    pub submission: Submission,
+    /// RFC 3339 timestamp of the submission.
+    pub submitted_at: String,
+}
+
+impl Attempt {
+    pub fn new(year: i16, day: u8, part: u8, answer: &str, submission: Submission) -> Attempt {
+        Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_owned(),
+            account: None,
+            submission,
+            submitted_at: chrono::Local::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        }
+    }
+
+    pub fn by(mut self, account: Option<&str>) -> Attempt {
+        self.account = account.map(str::to_owned);
+        self
+    }
+
+    /// Appends the attempt to [`SUBMISSIONS_PATH`] in the project root.
+    pub fn log(&self) -> io::Result<()> {
+        let line = serde_json::to_string(self).expect("attempts are always serializable");
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(project_root().join(SUBMISSIONS_PATH))?;
+        writeln!(file, "{}", line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article(text: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            text
+        )
+    }
+
+    #[test]
+    fn test_from_html() {
+        let cases = [
+            ("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit. <a href=\"/2022/day/5#part2\">[Continue to Part Two]</a>", Submission::Correct),
+            ("That's not the right answer.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again. <a href=\"/2022/day/5\">[Return to Day 5]</a>", Submission::Wrong),
+            ("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.", Submission::TooHigh),
+            ("That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data.", Submission::TooLow),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.", Submission::RateLimited { wait_secs: 34 }),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 2s left to wait.", Submission::RateLimited { wait_secs: 242 }),
+            ("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2022/day/5\">[Return to Day 5]</a>", Submission::AlreadySolved),
+        ];
+
+        for (text, expected) in cases {
+            assert_eq!(Submission::from_html(&article(text)), expected, "{}", text);
+        }
+        assert_eq!(
+            Submission::from_html(&article("Something <em>new</em>.")),
+            Submission::Unknown {
+                message: "Something new.".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn test_attempt_json() {
+        let attempt = Attempt {
+            year: 2022,
+            day: 5,
+            part: 1,
+            answer: "CMZ".to_owned(),
+            account: None,
+            submission: Submission::RateLimited { wait_secs: 34 },
+            submitted_at: "2022-12-05T08:00:00+01:00".to_owned(),
+        };
+        let json = serde_json::to_string(&attempt).unwrap();
+        assert_eq!(
+            json,
+            r#"{"year":2022,"day":5,"part":1,"answer":"CMZ","outcome":"rate_limited","wait_secs":34,"submitted_at":"2022-12-05T08:00:00+01:00"}"#
+        );
+        assert_eq!(serde_json::from_str::<Attempt>(&json).unwrap(), attempt);
+    }
+}
@@ -0,0 +1,136 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+//! When puzzles unlock. Every function takes the current time, so callers pass `Utc::now()`
+//! and tests pass a fixed instant.
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+
+/// Puzzles unlock at midnight EST (UTC-5), all year round.
+pub fn timezone() -> FixedOffset {
+    FixedOffset::west_opt(5 * 3600).unwrap()
+}
+
+/// The date in the puzzle timezone.
+pub fn today(now: DateTime<Utc>) -> NaiveDate {
+    now.with_timezone(&timezone()).date_naive()
+}
+
+/// How many puzzles an event has: 25 until 2024, 12 since 2025.
+pub fn days_in_event(year: i16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// When the puzzle of `day` in December of `year` unlocks.
+pub fn unlock_time(year: i16, day: u8) -> DateTime<Utc> {
+    timezone()
+        .with_ymd_and_hms(year as i32, 12, day as u32, 0, 0, 0)
+        .unwrap()
+        .with_timezone(&Utc)
+}
+
+pub fn is_unlocked(year: i16, day: u8, now: DateTime<Utc>) -> bool {
+    now >= unlock_time(year, day)
+}
+
+/// The puzzle that unlocked today, while an event is running.
+pub fn current_day(now: DateTime<Utc>) -> Option<(i16, u8)> {
+    let today = today(now);
+    let year = today.year() as i16;
+    let day = today.day() as u8;
+    (today.month() == 12 && day <= days_in_event(year)).then_some((year, day))
+}
+
+/// The next puzzle to unlock after `now`.
+pub fn next_unlock(now: DateTime<Utc>) -> (i16, u8) {
+    let today = today(now);
+    let year = today.year() as i16;
+    match current_day(now) {
+        Some((year, day)) if day < days_in_event(year) => (year, day + 1),
+        Some(_) => (year + 1, 1),
+        None if today.month() == 12 => (year + 1, 1),
+        None => (year, 1),
+    }
+}
+
+/// The next puzzle of the event of `year` to unlock after `now`, `None` once all of them did.
+pub fn next_unlock_in(year: i16, now: DateTime<Utc>) -> Option<u8> {
+    (1..=days_in_event(year)).find(|&day| !is_unlocked(year, day, now))
+}
+
+/// Time left until `year`/`day` unlocks, zero once it did.
+pub fn time_until(year: i16, day: u8, now: DateTime<Utc>) -> Duration {
+    (unlock_time(year, day) - now).to_std().unwrap_or_default()
+}
+
+/// `1d 02:03:04`, or `02:03:04` for less than a day.
+pub fn format_countdown(left: Duration) -> String {
+    let secs = left.as_secs();
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600 % 24,
+        secs / 60 % 60,
+        secs % 60
+    );
+    match secs / 86400 {
+        0 => clock,
+        days => format!("{}d {}", days, clock),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 5), utc("2022-12-05T05:00:00Z"));
+        assert!(!is_unlocked(2022, 5, utc("2022-12-05T04:59:59Z")));
+        assert!(is_unlocked(2022, 5, utc("2022-12-05T05:00:00Z")));
+    }
+
+    #[test]
+    fn test_current_and_next_day() {
+        // still December 4th in the puzzle timezone.
+        let now = utc("2022-12-05T04:00:00Z");
+        assert_eq!(current_day(now), Some((2022, 4)));
+        assert_eq!(next_unlock(now), (2022, 5));
+
+        let now = utc("2022-11-30T12:00:00Z");
+        assert_eq!(current_day(now), None);
+        assert_eq!(next_unlock(now), (2022, 1));
+
+        let now = utc("2022-12-25T12:00:00Z");
+        assert_eq!(current_day(now), Some((2022, 25)));
+        assert_eq!(next_unlock(now), (2023, 1));
+
+        let now = utc("2025-12-20T12:00:00Z");
+        assert_eq!(current_day(now), None);
+        assert_eq!(next_unlock(now), (2026, 1));
+
+        assert_eq!(next_unlock_in(2022, utc("2022-12-05T04:00:00Z")), Some(5));
+        assert_eq!(next_unlock_in(2023, utc("2022-12-05T04:00:00Z")), Some(1));
+        assert_eq!(next_unlock_in(2021, utc("2022-12-05T04:00:00Z")), None);
+    }
+
+    #[test]
+    fn test_countdown() {
+        let now = utc("2022-12-04T03:58:55Z");
+        assert_eq!(format_countdown(time_until(2022, 5, now)), "1d 01:01:05");
+        assert_eq!(
+            format_countdown(time_until(2022, 4, utc("2022-12-04T04:59:30Z"))),
+            "00:00:30"
+        );
+        assert_eq!(time_until(2022, 3, now), Duration::ZERO);
+    }
+}